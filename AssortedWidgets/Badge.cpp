@@ -0,0 +1,67 @@
+#include "Badge.h"
+#include <sstream>
+
+namespace AssortedWidgets
+{
+	namespace Widgets
+	{
+        Badge::Badge(unsigned int _count)
+            :m_count(_count),
+              m_overflow(99),
+              m_dotLimit(0),
+              m_showCount(true),
+              m_target(0)
+		{
+            m_horizontalStyle=Element::Fit;
+            m_verticalStyle=Element::Fit;
+            pack();
+		}
+
+        std::string Badge::getLabel() const
+		{
+            if(isDot())
+			{
+                return std::string();
+			}
+            std::ostringstream label;
+            if(m_overflow && m_count>m_overflow)
+			{
+                label<<m_overflow<<"+";
+			}
+			else
+			{
+                label<<m_count;
+			}
+            return label.str();
+		}
+
+        Element* Badge::getTarget()
+		{
+            if(m_target && (!m_parent || !getParent().contains(m_target)))
+			{
+                m_target=0;
+			}
+            return m_target;
+		}
+
+        Util::Position Badge::getDrawPosition()
+		{
+            Element *target=getTarget();
+            if(!target)
+			{
+                return m_position;
+			}
+            return Util::Position(target->m_position.x+static_cast<int>(target->m_size.m_width)-static_cast<int>(m_size.m_width/2),
+                                  target->m_position.y-static_cast<int>(m_size.m_height/2));
+		}
+
+		void Badge::paint()
+		{
+			Theme::ThemeEngine::getSingleton().getTheme().paintBadge(this);
+		}
+
+		Badge::~Badge(void)
+		{
+		}
+	}
+}
@@ -0,0 +1,107 @@
+#pragma once
+#include "ContainerElement.h"
+#include "ThemeEngine.h"
+#include <string>
+
+namespace AssortedWidgets
+{
+	namespace Widgets
+	{
+        class Badge:public Element
+		{
+		private:
+            unsigned int m_count;
+            unsigned int m_overflow;
+            unsigned int m_dotLimit;
+            bool m_showCount;
+            Element *m_target;
+		public:
+            Badge(unsigned int _count=0);
+
+            unsigned int getCount() const
+			{
+                return m_count;
+            }
+
+			void setCount(unsigned int _count)
+			{
+                m_count=_count;
+                pack();
+            }
+
+            unsigned int getOverflow() const
+			{
+                return m_overflow;
+            }
+
+			//counts above _overflow read as "99+" for the default of 99
+			void setOverflow(unsigned int _overflow)
+			{
+                m_overflow=_overflow;
+                pack();
+            }
+
+            unsigned int getDotLimit() const
+			{
+                return m_dotLimit;
+            }
+
+			//counts above _dotLimit are drawn as a plain dot, 0 never switches
+			void setDotLimit(unsigned int _dotLimit)
+			{
+                m_dotLimit=_dotLimit;
+                pack();
+            }
+
+            bool isShowCount() const
+			{
+                return m_showCount;
+            }
+
+			void setShowCount(bool _showCount)
+			{
+                m_showCount=_showCount;
+                pack();
+            }
+
+            bool isDot() const
+			{
+                return !m_showCount || (m_dotLimit && m_count>m_dotLimit);
+            }
+
+            std::string getLabel() const;
+
+			//draws the badge centered on the top right corner of _target instead of at its own location;
+			//both must be children of the same container and that container must have no layout,
+			//a layout would still reserve a cell for the badge. The target is dropped once it leaves the container
+			void attachTo(Element *_target)
+			{
+                m_target=_target;
+            }
+
+			void detach()
+			{
+                m_target=0;
+            }
+
+            Element* getTarget();
+
+			//where the badge is drawn, its own location unless attached
+			Util::Position getDrawPosition();
+
+			Util::Size getPreferedSize()
+			{
+				return Theme::ThemeEngine::getSingleton().getTheme().getBadgePreferedSize(this);
+            }
+
+			void pack()
+			{
+                m_size=getPreferedSize();
+            }
+
+			void paint();
+		public:
+			~Badge(void);
+		};
+	}
+}
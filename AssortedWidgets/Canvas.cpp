@@ -0,0 +1,125 @@
+#include "Canvas.h"
+#include "Panel.h"
+#include "Dialog.h"
+#include "ScrollPanel.h"
+#include "ThemeEngine.h"
+#include "Graphics.h"
+#include <algorithm>
+
+namespace AssortedWidgets
+{
+	namespace Widgets
+	{
+        Canvas::Canvas(unsigned int _width,unsigned int _height)
+            :Element(),
+              m_preferedSize(_width,_height),
+              m_clip(true)
+		{
+            m_size=m_preferedSize;
+            setHorizontalStyle(Element::Fit);
+            setVerticalStyle(Element::Fit);
+
+            mousePressedHandlerList.push_back(MOUSE_DELEGATE(Canvas::forwardMouse));
+            mouseReleasedHandlerList.push_back(MOUSE_DELEGATE(Canvas::forwardMouse));
+            mouseEnteredHandlerList.push_back(MOUSE_DELEGATE(Canvas::forwardMouse));
+            mouseExitedHandlerList.push_back(MOUSE_DELEGATE(Canvas::forwardMouse));
+            mouseMovedHandlerList.push_back(MOUSE_DELEGATE(Canvas::forwardMouse));
+            mouseWheelHandlerList.push_back(MOUSE_DELEGATE(Canvas::forwardWheel));
+		}
+
+        void Canvas::forwardMouse(const Event::MouseEvent &e)
+		{
+            if(m_mouseDelegate)
+			{
+                Event::MouseEvent event(this,e.getType(),e.getX()-m_position.x,e.getY()-m_position.y,e.getButton());
+                m_mouseDelegate(event);
+			}
+		}
+
+        void Canvas::forwardWheel(const Event::MouseEvent &e)
+		{
+            if(m_wheelDelegate)
+			{
+                Event::MouseEvent event(this,e.getType(),e.getX()-m_position.x,e.getY()-m_position.y,e.getButton());
+                m_wheelDelegate(event);
+                e.consume();
+			}
+		}
+
+        //the canvas bounds clamped to the area the parent clips its children to, false when nothing is left
+        bool Canvas::getClipArea(Util::Position &position,Util::Size &area)
+		{
+            int x1=m_position.x;
+            int y1=m_position.y;
+            int x2=m_position.x+static_cast<int>(m_size.m_width);
+            int y2=m_position.y+static_cast<int>(m_size.m_height);
+
+            Util::Position parentPosition;
+            Util::Size parentSize;
+            bool clamped=true;
+            if(Panel *panel=dynamic_cast<Panel*>(m_parent))
+			{
+                parentPosition=panel->getContentPosition();
+                parentSize=panel->getContentSize();
+			}
+            else if(Dialog *dialog=dynamic_cast<Dialog*>(m_parent))
+			{
+                parentPosition=dialog->getContentPosition();
+                parentSize=dialog->getContentSize();
+			}
+            else if(ScrollPanel *scrollPanel=dynamic_cast<ScrollPanel*>(m_logicalParent))
+			{
+                parentPosition=scrollPanel->getViewportPosition();
+                parentSize=scrollPanel->getViewportSize();
+			}
+            else
+			{
+                clamped=false;
+			}
+
+            if(clamped)
+			{
+                x1=std::max<int>(x1,parentPosition.x);
+                y1=std::max<int>(y1,parentPosition.y);
+                x2=std::min<int>(x2,parentPosition.x+static_cast<int>(parentSize.m_width));
+                y2=std::min<int>(y2,parentPosition.y+static_cast<int>(parentSize.m_height));
+			}
+            if(x2<=x1 || y2<=y1)
+			{
+                return false;
+			}
+            position=Util::Position(x1,y1);
+            area=Util::Size(x2-x1,y2-y1);
+            return true;
+		}
+
+        void Canvas::paint()
+		{
+            if(!m_paintDelegate)
+			{
+                return;
+			}
+            Util::Position clipPosition;
+            Util::Size clipArea;
+            if(m_clip && !getClipArea(clipPosition,clipArea))
+			{
+                return;
+			}
+            Util::Position origin=Util::Graphics::getSingleton().getOrigin();
+            Util::Position position(origin.x+m_position.x,origin.y+m_position.y);
+            if(m_clip)
+			{
+                Theme::ThemeEngine::getSingleton().getTheme().scissorBegin(clipPosition,clipArea);
+			}
+            m_paintDelegate(position,m_size);
+            if(m_clip)
+			{
+                Theme::ThemeEngine::getSingleton().getTheme().scissorEnd();
+			}
+		}
+
+		Canvas::~Canvas(void)
+		{
+		}
+	}
+}
@@ -0,0 +1,66 @@
+#pragma once
+#include "ContainerElement.h"
+#include "MouseEvent.h"
+#include <functional>
+
+namespace AssortedWidgets
+{
+	namespace Widgets
+	{
+        class Canvas: public Element
+		{
+		public:
+            //origin is in screen coordinates, ready for GraphicsBackend
+            typedef std::function<void(const Util::Position &origin,const Util::Size &area)> PaintDelegate;
+            //the event carries canvas-local coordinates
+            typedef std::function<void(const Event::MouseEvent &e)> MouseDelegate;
+		private:
+            PaintDelegate m_paintDelegate;
+            MouseDelegate m_mouseDelegate;
+            MouseDelegate m_wheelDelegate;
+            Util::Size m_preferedSize;
+            bool m_clip;
+
+            bool getClipArea(Util::Position &position,Util::Size &area);
+            void forwardMouse(const Event::MouseEvent &e);
+            void forwardWheel(const Event::MouseEvent &e);
+		public:
+            Canvas(unsigned int _width,unsigned int _height);
+
+			void setPaintDelegate(PaintDelegate _paintDelegate)
+			{
+                m_paintDelegate=_paintDelegate;
+            }
+
+			//receives presses, releases, enter, exit and motion
+			void setMouseDelegate(MouseDelegate _mouseDelegate)
+			{
+                m_mouseDelegate=_mouseDelegate;
+            }
+
+			void setWheelDelegate(MouseDelegate _wheelDelegate)
+			{
+                m_wheelDelegate=_wheelDelegate;
+            }
+
+			void setClip(bool _clip)
+			{
+                m_clip=_clip;
+            }
+
+            bool isClip() const
+			{
+                return m_clip;
+            }
+
+            Util::Size getPreferedSize()
+			{
+                return m_preferedSize;
+            }
+
+            void paint();
+		public:
+			~Canvas(void);
+		};
+	}
+}
@@ -7,7 +7,9 @@ namespace AssortedWidgets
 	{			
         CheckButton::CheckButton(const std::string &_text,bool _check)
             :m_text(_text),
-              m_check(_check)
+              m_check(_check),
+              m_indeterminate(false),
+              m_checkOnIndeterminateClick(true)
 		{
             m_size=getPreferedSize();
             m_horizontalStyle=Element::Fit;
@@ -19,7 +21,15 @@ namespace AssortedWidgets
 		void CheckButton::mouseReleased(const Event::MouseEvent &e)
 		{
             (void) e;
-            m_check=!m_check;
+            if(m_indeterminate)
+			{
+                m_indeterminate=false;
+                m_check=m_checkOnIndeterminateClick;
+			}
+			else
+			{
+                m_check=!m_check;
+			}
 		}
 
 		CheckButton::~CheckButton(void)
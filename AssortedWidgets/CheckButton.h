@@ -10,6 +10,8 @@ namespace AssortedWidgets
 		private:
             std::string m_text;
             bool m_check;
+            bool m_indeterminate;
+            bool m_checkOnIndeterminateClick;
 		public:
             bool isCheck() const
 			{
@@ -19,6 +21,33 @@ namespace AssortedWidgets
 			void setCheck(bool _check)
 			{
                 m_check=_check;
+                m_indeterminate=false;
+            }
+
+            bool isIndeterminate() const
+			{
+                return m_indeterminate;
+            }
+
+			//an indeterminate button is never checked, so isCheck() and isIndeterminate() tell the three states apart
+			void setIndeterminate(bool _indeterminate)
+			{
+                m_indeterminate=_indeterminate;
+                if(m_indeterminate)
+				{
+                    m_check=false;
+				}
+            }
+
+            bool isCheckOnIndeterminateClick() const
+			{
+                return m_checkOnIndeterminateClick;
+            }
+
+			//whether a click on an indeterminate button checks it (default) or unchecks it
+			void setCheckOnIndeterminateClick(bool _checkOnIndeterminateClick)
+			{
+                m_checkOnIndeterminateClick=_checkOnIndeterminateClick;
             }
 
             const std::string& getText() const
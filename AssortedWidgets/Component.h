@@ -35,6 +35,27 @@ namespace AssortedWidgets
 			virtual void paint()
             {}
 
+			void setEnable(bool _enable)
+			{
+                m_isEnable=_enable;
+            }
+
+            bool isEnable() const
+			{
+                return m_isEnable;
+            }
+
+			//hidden components keep their place in the layout but are neither painted nor hit
+			void setVisible(bool _visible)
+			{
+                m_isVisible=_visible;
+            }
+
+            bool isVisible() const
+			{
+                return m_isVisible;
+            }
+
 			void setLayoutProperty(int _layoutProperty)
 			{
                 m_layoutProperty=_layoutProperty;
@@ -111,6 +132,15 @@ namespace AssortedWidgets
 				}
             }
 
+			void processMouseWheel(const Event::MouseEvent& e)
+			{
+				std::vector<MouseDelegate>::iterator iter;
+				for(iter=mouseWheelHandlerList.begin();iter<mouseWheelHandlerList.end();++iter)
+				{
+					(*iter)(e);
+				}
+            }
+
             //not const for now
             virtual Util::Size getPreferedSize() = 0;
             virtual void pack(){}
@@ -122,6 +152,7 @@ namespace AssortedWidgets
 			std::vector<MouseDelegate> mouseEnteredHandlerList;
 			std::vector<MouseDelegate> mouseExitedHandlerList;
 			std::vector<MouseDelegate> mouseMovedHandlerList;
+			std::vector<MouseDelegate> mouseWheelHandlerList;
 
 		public:
             virtual ~Component(void)
@@ -132,6 +163,7 @@ namespace AssortedWidgets
 				mouseEnteredHandlerList.clear();
 				mouseExitedHandlerList.clear();
 				mouseMovedHandlerList.clear();
+				mouseWheelHandlerList.clear();
             }
 		};
 	}
@@ -15,6 +15,7 @@ namespace AssortedWidgets
 		{
 		protected:
             Container *m_parent;
+            Component *m_logicalParent;
             int m_horizontalStyle;
             int m_verticalStyle;
 		public:
@@ -27,6 +28,7 @@ namespace AssortedWidgets
 
             Element()
                 :m_parent(NULL),
+                  m_logicalParent(NULL),
                   m_horizontalStyle(0),
                   m_verticalStyle(0)
             {}
@@ -61,6 +63,27 @@ namespace AssortedWidgets
                 return *m_parent;
             }
 
+			//owner of an element that is not a container child, such as scroll panel content or the parts of a scroll bar;
+			//takes precedence over the container when walking up for enabled and visible state
+			void setLogicalParent(Component *_logicalParent)
+			{
+                m_logicalParent=_logicalParent;
+            }
+
+			Component* getLogicalParent()
+			{
+                return m_logicalParent;
+            }
+
+			//false if this element or any of its containers is disabled
+            bool isEnableInTree() const;
+
+			//false if this element or any of its containers is hidden
+            bool isVisibleInTree() const;
+		private:
+            Component* getTreeParent() const;
+		public:
+
             virtual ~Element()
             {}
 		};
@@ -78,6 +101,7 @@ namespace AssortedWidgets
 			void add(Element *child)
 			{
 				childList.push_back(child);
+                child->setParent(this);
             }
 			void setLayout(Layout::Layout *_layout)
 			{
@@ -86,6 +110,10 @@ namespace AssortedWidgets
 					delete layout;
 				}
 				layout=_layout;
+            }
+			bool contains(Element *child) const
+			{
+				return std::find(childList.begin(), childList.end(),child) != childList.end();
             }
 			void remove(Element *child)
 			{
@@ -105,8 +133,59 @@ namespace AssortedWidgets
 				{
 					delete (*iter);
 				}*/
+				for(std::vector<Element*>::iterator iter=childList.begin();iter<childList.end();++iter)
+				{
+					(*iter)->setParent(0);
+				}
 				childList.clear();
             }
 		};
+
+        inline Component* Element::getTreeParent() const
+        {
+            if(m_logicalParent)
+            {
+                return m_logicalParent;
+            }
+            return m_parent;
+        }
+
+        inline bool Element::isEnableInTree() const
+        {
+            if(!m_isEnable)
+            {
+                return false;
+            }
+            Component *parent=getTreeParent();
+            while(parent)
+            {
+                if(!parent->isEnable())
+                {
+                    return false;
+                }
+                Element *element=dynamic_cast<Element*>(parent);
+                parent=element?element->getTreeParent():0;
+            }
+            return true;
+        }
+
+        inline bool Element::isVisibleInTree() const
+        {
+            if(!m_isVisible)
+            {
+                return false;
+            }
+            Component *parent=getTreeParent();
+            while(parent)
+            {
+                if(!parent->isVisible())
+                {
+                    return false;
+                }
+                Element *element=dynamic_cast<Element*>(parent);
+                parent=element?element->getTreeParent():0;
+            }
+            return true;
+        }
 	}
 }
@@ -30,7 +30,9 @@
 #include "DropListButton.h"
 #include "DropList.h"
 #include "DropListItem.h"
+#include "Badge.h"
 #include "GraphicsBackend.h"
+#include <sstream>
 
 namespace AssortedWidgets
 {
@@ -144,7 +146,7 @@ namespace AssortedWidgets
 			}
 			else
 			{
-				switch(component->getStatus())
+				switch(component->isEnable()?component->getStatus():Widgets::Menu::normal)
 				{
 					case Widgets::Menu::hover :
 					{
@@ -164,7 +166,14 @@ namespace AssortedWidgets
 					{
                         float x1=static_cast<float>(component->m_position.x);
                         float y1=static_cast<float>(component->m_position.y);
-                        Font::FontEngine::getSingleton().getFont().setColor(150,155,161);
+                        if(component->isEnable())
+						{
+                            Font::FontEngine::getSingleton().getFont().setColor(150,155,161);
+						}
+						else
+						{
+                            Font::FontEngine::getSingleton().getFont().setColor(137,155,145);
+						}
 						Font::FontEngine::getSingleton().getFont().drawString(static_cast<int>(x1+6),static_cast<int>(y1+6),component->getText());
 						break;
 					}
@@ -256,11 +265,18 @@ namespace AssortedWidgets
 		void DefaultTheme::paintMenuItemButton(Widgets::MenuItemButton *component)
 		{
 			Util::Position origin=Util::Graphics::getSingleton().getOrigin();
-			switch(component->getStatus())
+			switch(component->isEnable()?component->getStatus():Widgets::MenuItemButton::normal)
 			{
 				case Widgets::MenuItemButton::normal:
 				{
-                    Font::FontEngine::getSingleton().getFont().setColor(255,255,255);
+                    if(component->isEnable())
+                    {
+                        Font::FontEngine::getSingleton().getFont().setColor(255,255,255);
+                    }
+                    else
+                    {
+                        Font::FontEngine::getSingleton().getFont().setColor(137,155,145);
+                    }
                     Font::FontEngine::getSingleton().getFont().drawString(static_cast<int>(component->m_position.x+component->getLeft()+origin.x),static_cast<int>(component->getTop()+origin.y+component->m_position.y),component->getText());
 					break;
 				};
@@ -293,11 +309,18 @@ namespace AssortedWidgets
 		void DefaultTheme::paintMenuItemSubMenu(Widgets::MenuItemSubMenu *component)
 		{
 			Util::Position origin=Util::Graphics::getSingleton().getOrigin();
-			switch(component->getStatus())
+			switch(component->isEnable()?component->getStatus():Widgets::MenuItemSubMenu::normal)
 			{
 				case Widgets::MenuItemSubMenu::normal:
 				{
-                    Font::FontEngine::getSingleton().getFont().setColor(255,255,255);
+                    if(component->isEnable())
+                    {
+                        Font::FontEngine::getSingleton().getFont().setColor(255,255,255);
+                    }
+                    else
+                    {
+                        Font::FontEngine::getSingleton().getFont().setColor(137,155,145);
+                    }
                     Font::FontEngine::getSingleton().getFont().drawString(static_cast<int>(component->m_position.x+component->getLeft()+origin.x),static_cast<int>(component->getTop()+origin.y+component->m_position.y),component->getText());
 
 					if(component->isExpand())
@@ -347,6 +370,7 @@ namespace AssortedWidgets
 			void DefaultTheme::paintLabel(Widgets::Label *component)
 			{
 				Util::Position origin=Util::Graphics::getSingleton().getOrigin();
+                bool enabled=component->isEnableInTree();
 				if(component->isDrawBackground())
 				{
                     GraphicsBackend::getSingleton().drawSolidQuad(static_cast<GLfloat>(origin.x+component->m_position.x),
@@ -355,7 +379,14 @@ namespace AssortedWidgets
                                                                   static_cast<GLfloat>(origin.y+component->m_position.y+component->m_size.m_height),
                                                                   0,0,0);
 				}
-                Font::FontEngine::getSingleton().getFont().setColor(255,255,255);
+                if(enabled)
+				{
+                    Font::FontEngine::getSingleton().getFont().setColor(255,255,255);
+				}
+				else
+				{
+                    Font::FontEngine::getSingleton().getFont().setColor(137,155,145);
+				}
                 Font::FontEngine::getSingleton().getFont().drawString(origin.x+component->m_position.x+component->getLeft(),origin.y+component->m_position.y+component->getTop(),component->getText());
             }
 
@@ -368,7 +399,8 @@ namespace AssortedWidgets
 			void DefaultTheme::paintButton(Widgets::Button *component)
 			{
 				Util::Position origin=Util::Graphics::getSingleton().getOrigin();
-				switch(component->getStatus())
+                bool enabled=component->isEnableInTree();
+				switch(enabled?component->getStatus():Widgets::Button::normal)
 				{
 					case Widgets::Button::normal:
 					{
@@ -379,7 +411,7 @@ namespace AssortedWidgets
                                                                       static_cast<float>(origin.y+component->m_position.y),
                                                                       static_cast<float>(origin.x+component->m_position.x+component->m_size.m_width-4),
                                                                       static_cast<float>(origin.y+component->m_position.y+19),
-                                                                      55,67,65);
+                                                                      enabled?55:46,enabled?67:55,enabled?65:53);
 
 
                         Font::FontEngine::getSingleton().getFont().setColor(137,155,145);
@@ -430,11 +462,18 @@ namespace AssortedWidgets
 			void DefaultTheme::paintMenuItemToggleButton(Widgets::MenuItemToggleButton *component)
 			{
 				Util::Position origin=Util::Graphics::getSingleton().getOrigin();
-				switch(component->getStatus())
+				switch(component->isEnable()?component->getStatus():Widgets::MenuItemToggleButton::normal)
 				{
 					case Widgets::MenuItemToggleButton::normal:
 					{
-                        Font::FontEngine::getSingleton().getFont().setColor(255,255,255);
+                        if(component->isEnable())
+                        {
+                            Font::FontEngine::getSingleton().getFont().setColor(255,255,255);
+                        }
+                        else
+                        {
+                            Font::FontEngine::getSingleton().getFont().setColor(137,155,145);
+                        }
                         Font::FontEngine::getSingleton().getFont().drawString(static_cast<int>(10+component->m_position.x+component->getLeft()+origin.x),static_cast<int>(component->getTop()+origin.y+component->m_position.y),component->getText());
 						if(component->getToggle())
 						{
@@ -480,11 +519,18 @@ namespace AssortedWidgets
 			void DefaultTheme::paintMenuItemRadioButton(Widgets::MenuItemRadioButton *component)
 			{
 				Util::Position origin=Util::Graphics::getSingleton().getOrigin();
-				switch(component->getStatus())
+				switch(component->isEnable()?component->getStatus():Widgets::MenuItemRadioButton::normal)
 				{
 					case Widgets::MenuItemRadioButton::normal:
 					{
-                        Font::FontEngine::getSingleton().getFont().setColor(255,255,255);
+                        if(component->isEnable())
+                        {
+                            Font::FontEngine::getSingleton().getFont().setColor(255,255,255);
+                        }
+                        else
+                        {
+                            Font::FontEngine::getSingleton().getFont().setColor(137,155,145);
+                        }
                         Font::FontEngine::getSingleton().getFont().drawString(static_cast<int>(10+component->m_position.x+component->getLeft()+origin.x),static_cast<int>(component->getTop()+origin.y+component->m_position.y),component->getText());
 						if(component->getToggle())
 						{
@@ -619,9 +665,17 @@ namespace AssortedWidgets
 
                 GraphicsBackend::getSingleton().drawSolidQuad(x2,y1,x3,y2,79,91,84);
 
-				if(component->isActive())
+				if(component->isActive() && component->isEnableInTree() && ((SDL_GetTicks()-component->getBlinkStart())/530)%2==0)
 				{
                     GraphicsBackend::getSingleton().drawSolidQuad(x3+2,y1+4,x3+3,y2-4,0,0,0);
+				}
+				if(component->isEnableInTree())
+				{
+                    Font::FontEngine::getSingleton().getFont().setColor(255,255,255);
+				}
+				else
+				{
+                    Font::FontEngine::getSingleton().getFont().setColor(137,155,145);
 				}
 				Util::Size textSize=Font::FontEngine::getSingleton().getFont().getStringBoundingBox(component->getText());
                 glEnable(GL_SCISSOR_TEST);
@@ -651,19 +705,20 @@ namespace AssortedWidgets
 			void DefaultTheme::paintScrollBarButton(Widgets::ScrollBarButton *component)
 			{
 				SubImage *button=0;
+                int status=component->isEnableInTree()?component->getStatus():Widgets::ScrollBarButton::normal;
 				switch(component->getType())
 				{
 					case Widgets::ScrollBarButton::HorizontalLeft:
 					{
-						if(component->getStatus()==Widgets::ScrollBarButton::normal)
+						if(status==Widgets::ScrollBarButton::normal)
 						{
                             button = m_ScrollBarHorizontalLeftNormal;
 						}
-						else if(component->getStatus()==Widgets::ScrollBarButton::hover)
+						else if(status==Widgets::ScrollBarButton::hover)
 						{
                             button = m_ScrollBarHorizontalLeftHover;
 						}
-						else if(component->getStatus()==Widgets::ScrollBarButton::pressed)
+						else if(status==Widgets::ScrollBarButton::pressed)
 						{
                             button = m_ScrollBarHorizontalLeftNormal;
 						}
@@ -671,15 +726,15 @@ namespace AssortedWidgets
 					}
 					case Widgets::ScrollBarButton::HorizontalRight:
 					{
-						if(component->getStatus()==Widgets::ScrollBarButton::normal)
+						if(status==Widgets::ScrollBarButton::normal)
 						{
                             button = m_ScrollBarHorizontalRightNormal;
 						}
-						else if(component->getStatus()==Widgets::ScrollBarButton::hover)
+						else if(status==Widgets::ScrollBarButton::hover)
 						{
                             button = m_ScrollBarHorizontalRightHover;
 						}
-						else if(component->getStatus()==Widgets::ScrollBarButton::pressed)
+						else if(status==Widgets::ScrollBarButton::pressed)
 						{
                             button = m_ScrollBarHorizontalRightNormal;
 						}
@@ -687,15 +742,15 @@ namespace AssortedWidgets
 					}
 					case Widgets::ScrollBarButton::VerticalTop:
 					{
-						if(component->getStatus()==Widgets::ScrollBarButton::normal)
+						if(status==Widgets::ScrollBarButton::normal)
 						{
                             button = m_ScrollBarVerticalTopNormal;
 						}
-						else if(component->getStatus()==Widgets::ScrollBarButton::hover)
+						else if(status==Widgets::ScrollBarButton::hover)
 						{
                             button = m_ScrollBarVerticalTopHover;
 						}
-						else if(component->getStatus()==Widgets::ScrollBarButton::pressed)
+						else if(status==Widgets::ScrollBarButton::pressed)
 						{
                             button = m_ScrollBarVerticalTopNormal;
 						}
@@ -703,15 +758,15 @@ namespace AssortedWidgets
 					}
 					case Widgets::ScrollBarButton::VerticalBottom:
 					{
-						if(component->getStatus()==Widgets::ScrollBarButton::normal)
+						if(status==Widgets::ScrollBarButton::normal)
 						{
                             button = m_ScrollBarVerticalBottomNormal;
 						}
-						else if(component->getStatus()==Widgets::ScrollBarButton::hover)
+						else if(status==Widgets::ScrollBarButton::hover)
 						{
                             button = m_ScrollBarVerticalBottomHover;
 						}
-						else if(component->getStatus()==Widgets::ScrollBarButton::pressed)
+						else if(status==Widgets::ScrollBarButton::pressed)
 						{
                             button = m_ScrollBarVerticalBottomNormal;
 						}
@@ -731,12 +786,13 @@ namespace AssortedWidgets
 			void DefaultTheme::paintScrollBarSlider(Widgets::ScrollBarSlider *component)
 			{
 				Util::Position origin=Util::Graphics::getSingleton().getOrigin();
+                bool enabled=component->getScrollBar()->isEnableInTree();
 
                 GraphicsBackend::getSingleton().drawSolidQuad(static_cast<float>(origin.x+component->m_position.x),
                                                               static_cast<float>(origin.y+component->m_position.y),
                                                               static_cast<float>(origin.x+component->m_position.x+component->m_size.m_width),
                                                               static_cast<float>(origin.y+component->m_position.y+component->m_size.m_height),
-                                                              46,55,53);
+                                                              enabled?46:137,enabled?55:155,enabled?53:145);
             }
 
             Util::Size DefaultTheme::getScrollBarPreferedSize(Widgets::ScrollBar *)
@@ -807,6 +863,7 @@ namespace AssortedWidgets
 			void DefaultTheme::paintCheckButton(Widgets::CheckButton *component)
 			{
 				Util::Position origin=Util::Graphics::getSingleton().getOrigin();
+                bool enabled=component->isEnableInTree();
 				SubImage *checkStatus(0);
 				if(component->isCheck())
 				{
@@ -816,7 +873,7 @@ namespace AssortedWidgets
 				{
                     checkStatus=m_CheckButtonOff;
 				}
-				switch(component->getStatus())
+				switch(enabled?component->getStatus():Widgets::CheckButton::normal)
 				{
 					case Widgets::CheckButton::normal:
 					{
@@ -828,7 +885,7 @@ namespace AssortedWidgets
                                                                       static_cast<float>(origin.y+component->m_position.y),
                                                                       static_cast<float>(origin.x+component->m_position.x+component->m_size.m_width-4),
                                                                       static_cast<float>(origin.y+component->m_position.y+19),
-                                                                      55,67,65);
+                                                                      enabled?55:46,enabled?67:55,enabled?65:53);
 
                         Font::FontEngine::getSingleton().getFont().setColor(137,155,145);
                         Font::FontEngine::getSingleton().getFont().drawString(origin.x+component->m_position.x+component->getLeft()+15,origin.y+component->m_position.y+component->getTop(),component->getText());
@@ -873,6 +930,20 @@ namespace AssortedWidgets
 						break;
 					};
 				}
+
+				if(component->isIndeterminate())
+				{
+                    float x1=static_cast<float>(origin.x+component->m_position.x+component->getLeft()+3);
+                    float y1=static_cast<float>(origin.y+component->m_position.y+component->getTop()+5);
+                    if(!enabled || component->getStatus()==Widgets::CheckButton::normal)
+					{
+                        GraphicsBackend::getSingleton().drawSolidQuad(x1,y1,x1+5,y1+2,137,155,145);
+					}
+					else
+					{
+                        GraphicsBackend::getSingleton().drawSolidQuad(x1,y1,x1+5,y1+2,0,0,0);
+					}
+				}
             }
 
 			Util::Size DefaultTheme::getRadioButtonPreferedSize(Widgets::RadioButton *component)
@@ -884,6 +955,7 @@ namespace AssortedWidgets
 			void DefaultTheme::paintRadioButton(Widgets::RadioButton *component)
 			{
 				Util::Position origin=Util::Graphics::getSingleton().getOrigin();
+                bool enabled=component->isEnableInTree();
 				SubImage *checkStatus(0);
 				if(component->isCheck())
 				{
@@ -893,7 +965,7 @@ namespace AssortedWidgets
 				{
                     checkStatus=m_RadioButtonOff;
 				}
-				switch(component->getStatus())
+				switch(enabled?component->getStatus():Widgets::CheckButton::normal)
 				{
 					case Widgets::CheckButton::normal:
 					{
@@ -904,7 +976,7 @@ namespace AssortedWidgets
                                                                       static_cast<float>(origin.y+component->m_position.y),
                                                                       static_cast<float>(origin.x+component->m_position.x+component->m_size.m_width-4),
                                                                       static_cast<float>(origin.y+component->m_position.y+19),
-                                                                      55,67,65);
+                                                                      enabled?55:46,enabled?67:55,enabled?65:53);
 
                         Font::FontEngine::getSingleton().getFont().setColor(137,155,145);
                         Font::FontEngine::getSingleton().getFont().drawString(origin.x+component->m_position.x+component->getLeft()+15,origin.y+component->m_position.y+component->getTop(),component->getText());
@@ -960,6 +1032,7 @@ namespace AssortedWidgets
 			void DefaultTheme::paintProgressBar(Widgets::ProgressBar *component)
 			{
 				Util::Position origin=Util::Graphics::getSingleton().getOrigin();
+                bool enabled=component->isEnableInTree();
 				if(component->getType()==Widgets::ProgressBar::Horizontal)
 				{
 					Util::Position origin=Util::Graphics::getSingleton().getOrigin();
@@ -974,7 +1047,18 @@ namespace AssortedWidgets
                     m_ProgressBarRight->paint(x3,y1,x4,y2);
 
                     GraphicsBackend::getSingleton().drawSolidQuad(x2,y1,x3,y2,79,91,84);
-                    GraphicsBackend::getSingleton().drawSolidQuad(x1+2,y1+2,x1+2+component->getPOfSlider(),y2-2,46,55,53);
+                    if(component->isIndeterminate())
+					{
+                        float length=x4-x1-4.0f;
+                        float block=length*0.25f;
+                        float phase=static_cast<float>(SDL_GetTicks()%component->getSweepPeriod())/component->getSweepPeriod();
+                        float start=x1+2.0f+phase*(length+block)-block;
+                        GraphicsBackend::getSingleton().drawSolidQuad(std::max(start,x1+2.0f),y1+2,std::min(start+block,x4-2.0f),y2-2,enabled?46:137,enabled?55:155,enabled?53:145);
+					}
+					else
+					{
+                        GraphicsBackend::getSingleton().drawSolidQuad(x1+2,y1+2,x1+2+component->getPOfSlider(),y2-2,enabled?46:137,enabled?55:155,enabled?53:145);
+					}
 				}
 				else if(component->getType()==Widgets::ProgressBar::Vertical)
 				{
@@ -991,7 +1075,33 @@ namespace AssortedWidgets
 
                     GraphicsBackend::getSingleton().drawSolidQuad(x1,y2,x2,y3,79,91,84);
 
-                    GraphicsBackend::getSingleton().drawSolidQuad(x1+2,y4-2-component->getPOfSlider(),x2-2,y4-2,46,55,53);
+                    if(component->isIndeterminate())
+					{
+                        float length=y4-y1-4.0f;
+                        float block=length*0.25f;
+                        float phase=static_cast<float>(SDL_GetTicks()%component->getSweepPeriod())/component->getSweepPeriod();
+                        float start=y4-2.0f-phase*(length+block)+block;
+                        GraphicsBackend::getSingleton().drawSolidQuad(x1+2,std::max(start-block,y1+2.0f),x2-2,std::min(start,y4-2.0f),enabled?46:137,enabled?55:155,enabled?53:145);
+					}
+					else
+					{
+                        GraphicsBackend::getSingleton().drawSolidQuad(x1+2,y4-2-component->getPOfSlider(),x2-2,y4-2,enabled?46:137,enabled?55:155,enabled?53:145);
+					}
+				}
+
+				if(component->isShowLabel() && !component->isIndeterminate())
+				{
+					std::ostringstream label;
+					label<<static_cast<int>(component->getPercent()*100.0f+0.5f)<<"%";
+					Util::Size textSize=Font::FontEngine::getSingleton().getFont().getStringBoundingBox(label.str());
+					//narrow bars, such as the default vertical one, have no room for the text
+					if(textSize.m_width+4<=component->m_size.m_width && textSize.m_height<=component->m_size.m_height)
+					{
+                        int x=origin.x+component->m_position.x+(static_cast<int>(component->m_size.m_width)-static_cast<int>(textSize.m_width))/2;
+                        int y=origin.y+component->m_position.y+(static_cast<int>(component->m_size.m_height)-static_cast<int>(textSize.m_height))/2;
+                        Font::FontEngine::getSingleton().getFont().setColor(enabled?255:137,enabled?255:155,enabled?255:145);
+                        Font::FontEngine::getSingleton().getFont().drawString(x,y,label.str());
+					}
 				}
             }
 
@@ -1004,12 +1114,13 @@ namespace AssortedWidgets
 			void DefaultTheme::paintSlideBarSlider(Widgets::SlideBarSlider *component)
 			{
 				Util::Position origin=Util::Graphics::getSingleton().getOrigin();
+                bool enabled=component->getSlideBar()->isEnableInTree();
 
                 GraphicsBackend::getSingleton().drawSolidQuad(static_cast<float>(origin.x+component->m_position.x),
                                                               static_cast<float>(origin.y+component->m_position.y),
                                                               static_cast<float>(origin.x+component->m_position.x+component->m_size.m_width),
                                                               static_cast<float>(origin.y+component->m_position.y+component->m_size.m_height),
-                                                              46,55,53);
+                                                              enabled?46:137,enabled?55:155,enabled?53:145);
             }
 
 			Util::Size DefaultTheme::getSlideBarPreferedSize(Widgets::SlideBar *component)
@@ -1021,6 +1132,7 @@ namespace AssortedWidgets
 			void DefaultTheme::paintSlideBar(Widgets::SlideBar *component)
 			{
 				Util::Position origin=Util::Graphics::getSingleton().getOrigin();
+                bool enabled=component->isEnableInTree();
 				if(component->getType()==Widgets::SlideBar::Horizontal)
 				{
 					Util::Position origin=Util::Graphics::getSingleton().getOrigin();
@@ -1036,6 +1148,17 @@ namespace AssortedWidgets
 
                     GraphicsBackend::getSingleton().drawSolidQuad(x2,y1,x3,y2,79,91,84);
 
+                    if(component->getTickCount()>1)
+					{
+                        float half=component->getSliderLength()*0.5f;
+                        float start=x1+2.0f+half;
+                        float length=x4-x1-4.0f-2.0f*half;
+                        for(unsigned int i=0;i<component->getTickCount();++i)
+						{
+                            float x=start+length*i/(component->getTickCount()-1);
+                            GraphicsBackend::getSingleton().drawLine(x,y2-6.0f,x,y2-2.0f,enabled?46:137,enabled?55:155,enabled?53:145);
+						}
+					}
 				}
 				else if(component->getType()==Widgets::SlideBar::Vertical)
 				{
@@ -1050,6 +1173,18 @@ namespace AssortedWidgets
                     m_ProgressBarBottom->paint(x1,y3,x2,y4);
 
                     GraphicsBackend::getSingleton().drawSolidQuad(x1,y2,x2,y3,79,91,84);
+
+                    if(component->getTickCount()>1)
+					{
+                        float half=component->getSliderLength()*0.5f;
+                        float start=y1+2.0f+half;
+                        float length=y4-y1-4.0f-2.0f*half;
+                        for(unsigned int i=0;i<component->getTickCount();++i)
+						{
+                            float y=start+length*i/(component->getTickCount()-1);
+                            GraphicsBackend::getSingleton().drawLine(x2-6.0f,y,x2-2.0f,y,enabled?46:137,enabled?55:155,enabled?53:145);
+						}
+					}
 				}
             }
 
@@ -1062,7 +1197,7 @@ namespace AssortedWidgets
 			void DefaultTheme::paintDropListButton(Widgets::DropListButton *component)
 			{
 				SubImage *button(0);
-				switch(component->getStatus())
+				switch(component->isEnableInTree()?component->getStatus():Widgets::DropListButton::normal)
 				{
 					case Widgets::DropListButton::normal:
 					{
@@ -1094,6 +1229,7 @@ namespace AssortedWidgets
 			void DefaultTheme::paintDropList(Widgets::DropList *component)
 			{
 				Util::Position origin=Util::Graphics::getSingleton().getOrigin();
+                bool enabled=component->isEnableInTree();
                 float x1=static_cast<float>(origin.x+component->m_position.x);
                 float x2=static_cast<float>(origin.x+component->m_position.x+4);
                 float x3=static_cast<float>(origin.x+component->m_position.x+component->m_size.m_width-4);
@@ -1109,7 +1245,14 @@ namespace AssortedWidgets
 				Widgets::DropListItem *selected(component->getSelectedItem());
 				if(selected)
 				{
-                    Font::FontEngine::getSingleton().getFont().setColor(0,0,0);
+                    if(enabled)
+					{
+                        Font::FontEngine::getSingleton().getFont().setColor(0,0,0);
+					}
+					else
+					{
+                        Font::FontEngine::getSingleton().getFont().setColor(137,155,145);
+					}
                     Font::FontEngine::getSingleton().getFont().drawString(origin.x+component->m_position.x+component->getLeft(),origin.y+component->m_position.y+component->getTop(),selected->getText());
 				}
             }
@@ -1156,6 +1299,44 @@ namespace AssortedWidgets
                 GraphicsBackend::getSingleton().drawLineStrip(points, 46,55,53);
 
 
+            }
+
+			Util::Size DefaultTheme::getBadgePreferedSize(Widgets::Badge *component)
+			{
+                if(component->isDot())
+				{
+                    return Util::Size(8,8);
+				}
+				Util::Size text=Font::FontEngine::getSingleton().getFont().getStringBoundingBox(component->getLabel());
+                unsigned int height=text.m_height+2;
+                return Util::Size(std::max<unsigned int>(text.m_width+8,height),height);
+            }
+
+			void DefaultTheme::paintBadge(Widgets::Badge *component)
+			{
+				Util::Position origin=Util::Graphics::getSingleton().getOrigin();
+                Util::Position position=component->getDrawPosition();
+                float x1=static_cast<float>(origin.x+position.x);
+                float y1=static_cast<float>(origin.y+position.y);
+                float x2=x1+static_cast<float>(component->m_size.m_width);
+                float y2=y1+static_cast<float>(component->m_size.m_height);
+                //two overlapping quads cut the corners into a pill shape
+                GraphicsBackend::getSingleton().drawSolidQuad(x1+2,y1,x2-2,y2,204,51,51);
+                GraphicsBackend::getSingleton().drawSolidQuad(x1,y1+2,x2,y2-2,204,51,51);
+                if(!component->isDot())
+				{
+                    std::string label=component->getLabel();
+					Util::Size textSize=Font::FontEngine::getSingleton().getFont().getStringBoundingBox(label);
+                    int x=origin.x+position.x+(static_cast<int>(component->m_size.m_width)-static_cast<int>(textSize.m_width))/2;
+                    int y=origin.y+position.y+1;
+                    Font::FontEngine::getSingleton().getFont().setColor(255,255,255);
+                    Font::FontEngine::getSingleton().getFont().drawString(x,y,label);
+				}
+            }
+
+			void DefaultTheme::paintModalScrim()
+			{
+                GraphicsBackend::getSingleton().drawSolidQuad(0.0f,0.0f,static_cast<float>(m_screenWidth),static_cast<float>(m_screenHeight),0,0,0,0.4f);
             }
 
 		DefaultTheme::~DefaultTheme(void)
@@ -226,8 +226,14 @@ namespace AssortedWidgets
 			
 			void paintDropListItem(Widgets::DropListItem *component);
 
+			Util::Size getBadgePreferedSize(Widgets::Badge *component);
+
+			void paintBadge(Widgets::Badge *component);
+
 			void paintDropDown(Util::Position &position,Util::Size &area);
 
+			void paintModalScrim();
+
 			void test();
 		public:
 			DefaultTheme(void);
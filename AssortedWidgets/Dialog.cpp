@@ -42,6 +42,7 @@ namespace AssortedWidgets
             mouseReleasedHandlerList.push_back(MOUSE_DELEGATE(Dialog::mouseReleased));
             mouseEnteredHandlerList.push_back(MOUSE_DELEGATE(Dialog::mouseEntered));
             mouseExitedHandlerList.push_back(MOUSE_DELEGATE(Dialog::mouseEntered));
+            mouseWheelHandlerList.push_back(MOUSE_DELEGATE(Dialog::mouseWheel));
 
 			pack();
 		}
@@ -66,7 +67,7 @@ namespace AssortedWidgets
 			std::vector<Element*>::iterator iter;
 			for(iter=childList.begin();iter<childList.end();++iter)
 			{
-				if((*iter)->isIn(mx,my))
+				if(m_isEnable && (*iter)->isVisible() && (*iter)->isEnable() && (*iter)->isIn(mx,my))
 				{
 					Event::MouseEvent event((*iter),Event::MouseEvent::MOUSE_ENTERED,mx,my,0);
 					(*iter)->processMouseEntered(event);
@@ -99,7 +100,7 @@ namespace AssortedWidgets
 			std::vector<Element*>::iterator iter;
 			for(iter=childList.begin();iter<childList.end();++iter)
 			{
-				if((*iter)->isIn(mx,my))
+				if(m_isEnable && (*iter)->isVisible() && (*iter)->isEnable() && (*iter)->isIn(mx,my))
 				{
                     if((*iter)->m_isHover)
 					{
@@ -194,7 +195,7 @@ namespace AssortedWidgets
 			std::vector<Element*>::iterator iter;
 			for(iter=childList.begin();iter<childList.end();++iter)
 			{
-				if((*iter)->isIn(mx,my))
+				if(m_isEnable && (*iter)->isVisible() && (*iter)->isEnable() && (*iter)->isIn(mx,my))
 				{
 					Event::MouseEvent event((*iter),Event::MouseEvent::MOUSE_PRESSED,mx,my,0);
 					(*iter)->processMousePressed(event);
@@ -203,6 +204,26 @@ namespace AssortedWidgets
 			}
 		}
 
+		void Dialog::mouseWheel(const Event::MouseEvent &e)
+		{
+            int mx=e.getX()-m_position.x;
+            int my=e.getY()-m_position.y;
+			std::vector<Element*>::iterator iter;
+			for(iter=childList.begin();iter<childList.end();++iter)
+			{
+				if(m_isEnable && (*iter)->isVisible() && (*iter)->isEnable() && (*iter)->isIn(mx,my))
+				{
+					Event::MouseEvent event((*iter),Event::MouseEvent::MOUSE_WHEEL,mx,my,e.getButton());
+					(*iter)->processMouseWheel(event);
+					if(event.isConsumed())
+					{
+						e.consume();
+					}
+					break;
+				}
+			}
+		}
+
 		void Dialog::mouseReleased(const Event::MouseEvent &e)
 		{
             int mx=e.getX()-m_position.x;
@@ -210,7 +231,7 @@ namespace AssortedWidgets
 			std::vector<Element*>::iterator iter;
 			for(iter=childList.begin();iter<childList.end();++iter)
 			{
-				if((*iter)->isIn(mx,my))
+				if(m_isEnable && (*iter)->isVisible() && (*iter)->isEnable() && (*iter)->isIn(mx,my))
 				{
 					Event::MouseEvent event((*iter),Event::MouseEvent::MOUSE_RELEASED,mx,my,0);
 					(*iter)->processMouseReleased(event);
@@ -49,6 +49,14 @@ namespace AssortedWidgets
             Util::Size m_contentSize;
 			
 		public:
+            const Util::Position& getContentPosition() const
+			{
+                return m_contentPosition;
+            }
+            const Util::Size& getContentSize() const
+			{
+                return m_contentSize;
+            }
             void setShowType(enum ShowType _showType)
 			{
                 m_showType=_showType;
@@ -94,11 +102,16 @@ namespace AssortedWidgets
 			void mouseEntered(const Event::MouseEvent &e);
 			void mouseExited(const Event::MouseEvent &e);
 			void mouseMoved(const Event::MouseEvent &e);
+			void mouseWheel(const Event::MouseEvent &e);
 			void paintChild()
 			{
 				std::vector<Element*>::iterator iter;
 				for(iter=childList.begin();iter<childList.end();++iter)
 				{
+                    if(!(*iter)->isVisible())
+					{
+                        continue;
+					}
                     Theme::ThemeEngine::getSingleton().getTheme().scissorBegin(m_contentPosition,m_contentSize);
 					(*iter)->paint();
 					Theme::ThemeEngine::getSingleton().getTheme().scissorEnd();
@@ -1,5 +1,8 @@
 #include "DialogManager.h"
 #include "Dialog.h"
+#include "ThemeEngine.h"
+#include "DropListManager.h"
+#include "TypeActiveManager.h"
 
 namespace AssortedWidgets
 {
@@ -38,6 +41,9 @@ namespace AssortedWidgets
             m_modalDialog=_modalDialog;
             m_modalDialog->setActive(true);
             m_modalDialog->setShowType(Widgets::Dialog::Modal);
+            //input outside the modal dialog is blocked, so nothing from behind it may stay open or focused
+            DropListManager::getSingleton().shrinkBack();
+            TypeActiveManager::getSingleton().disactive();
 			std::vector<Widgets::Dialog*>::iterator iter;
             for(iter=m_modelessDialog.begin();iter<m_modelessDialog.end();++iter)
 			{
@@ -191,6 +197,33 @@ namespace AssortedWidgets
 			}
 		}
 
+		bool DialogManager::importMouseWheel(int mx,int my,int button)
+		{
+            if(m_modalDialog)
+			{
+                if(m_modalDialog->isIn(mx,my))
+				{
+                    Event::MouseEvent event(m_modalDialog,Event::MouseEvent::MOUSE_WHEEL,mx,my,button);
+                    m_modalDialog->processMouseWheel(event);
+                    return true;
+				}
+			}
+            else
+			{
+                //topmost first, the wheel goes to whichever dialog is under the pointer without raising it
+                for(int i=static_cast<int>(m_modelessDialog.size()-1);i>=0;--i)
+				{
+                    if(m_modelessDialog[i]->isIn(mx,my))
+					{
+                        Event::MouseEvent event(m_modelessDialog[i],Event::MouseEvent::MOUSE_WHEEL,mx,my,button);
+                        m_modelessDialog[i]->processMouseWheel(event);
+                        return true;
+					}
+				}
+			}
+            return false;
+		}
+
 		void DialogManager::importMouseReleased(int mx,int my)
 		{
             if(m_modalDialog)
@@ -227,6 +260,7 @@ namespace AssortedWidgets
 			}
             if(m_modalDialog)
 			{
+				Theme::ThemeEngine::getSingleton().getTheme().paintModalScrim();
                 m_modalDialog->paint();
 			}
 		}
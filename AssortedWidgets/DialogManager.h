@@ -25,9 +25,14 @@ namespace AssortedWidgets
 				return obj;
             }
 
+			bool hasModalDialog() const
+			{
+                return m_modalDialog!=0;
+			}
 			void importMouseMotion(int mx,int my);
 			void importMousePressed(int mx,int my);
 			void importMouseReleased(int mx,int my);
+			bool importMouseWheel(int mx,int my,int button);
 			void paint();
 		private:
 			DialogManager(void);
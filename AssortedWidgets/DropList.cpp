@@ -27,6 +27,7 @@ namespace AssortedWidgets
             mouseEnteredHandlerList.push_back(MOUSE_DELEGATE(DropList::mouseEntered));
             mouseExitedHandlerList.push_back(MOUSE_DELEGATE(DropList::mouseExited));
             mouseMovedHandlerList.push_back(MOUSE_DELEGATE(DropList::mouseMoved));
+            m_button.setLogicalParent(this);
             m_button.mouseReleasedHandlerList.push_back(MOUSE_DELEGATE(DropList::onDropReleased));
 		
 		}
@@ -144,6 +144,11 @@ void loop()
 						AssortedWidgets::UI::getSingleton().importMousePress(event.button.button,event.button.x,event.button.y);
 						break;
 					}
+					case SDL_MOUSEWHEEL:
+					{
+						AssortedWidgets::UI::getSingleton().importMouseWheel(event.wheel.y);
+						break;
+					}
 					case SDL_KEYDOWN:
 					{
                         AssortedWidgets::UI::getSingleton().importKeyDown(event.key.keysym.sym,event.key.keysym.mod);
@@ -65,7 +65,7 @@ namespace AssortedWidgets
 			std::vector<Menu*>::iterator iter;
             for(iter=m_menuList.begin();iter<m_menuList.end();++iter)
 			{
-				if((*iter)->isIn(e.getX(),e.getY()))
+				if((*iter)->isEnable() && (*iter)->isIn(e.getX(),e.getY()))
 				{
 					Event::MouseEvent event((*iter),Event::MouseEvent::MOUSE_PRESSED,e.getX(),e.getY(),0);
 					(*iter)->processMousePressed(event);
@@ -83,7 +83,7 @@ namespace AssortedWidgets
 			std::vector<Menu*>::iterator iter;
             for(iter=m_menuList.begin();iter<m_menuList.end();++iter)
 			{
-				if((*iter)->isIn(e.getX(),e.getY()))
+				if((*iter)->isEnable() && (*iter)->isIn(e.getX(),e.getY()))
 				{
 					Event::MouseEvent event((*iter),Event::MouseEvent::MOUSE_RELEASED,e.getX(),e.getY(),0);
 					(*iter)->processMouseReleased(event);
@@ -81,7 +81,7 @@ namespace AssortedWidgets
 			std::vector<MenuItem*>::iterator iter;
             for(iter=m_itemList.begin();iter<m_itemList.end();++iter)
 			{
-				if((*iter)->isIn(mx,my))
+				if((*iter)->isEnable() && (*iter)->isIn(mx,my))
 				{
 					Event::MouseEvent event((*iter),Event::MouseEvent::MOUSE_PRESSED,mx,my,0);
 					(*iter)->processMousePressed(event);
@@ -102,7 +102,7 @@ namespace AssortedWidgets
 			std::vector<MenuItem*>::iterator iter;
             for(iter=m_itemList.begin();iter<m_itemList.end();++iter)
 			{
-				if((*iter)->isIn(mx,my))
+				if((*iter)->isEnable() && (*iter)->isIn(mx,my))
 				{
 					Event::MouseEvent event((*iter),Event::MouseEvent::MOUSE_RELEASED,mx,my,0);
 					(*iter)->processMouseReleased(event);
@@ -18,7 +18,8 @@ namespace AssortedWidgets
 				MOUSE_EXITED,
 				MOUSE_DRAGGED,
 				MOUSE_ENTERED,
-				MOUSE_MOTION
+				MOUSE_MOTION,
+				MOUSE_WHEEL
 			};
 
 			enum MouseButtons
@@ -34,9 +35,21 @@ namespace AssortedWidgets
                 :Event(_source,_type),
                   m_mouseX(_x),
                   m_mouseY(_y),
-                  m_mouseButton(_mouseButton)
+                  m_mouseButton(_mouseButton),
+                  m_consumed(false)
             {}
 
+			//a wheel handler that used the event marks it, so enclosing scroll panels leave it alone
+			void consume() const
+			{
+                m_consumed=true;
+            }
+
+            bool isConsumed() const
+			{
+                return m_consumed;
+            }
+
             int getButton() const
 			{
                 return m_mouseButton;
@@ -55,6 +68,7 @@ namespace AssortedWidgets
             int m_mouseX;
             int m_mouseY;
             int m_mouseButton;
+            mutable bool m_consumed;
 		public:
             ~MouseEvent(void){}
 		};
@@ -24,6 +24,7 @@ namespace AssortedWidgets
             mouseReleasedHandlerList.push_back(MOUSE_DELEGATE(Panel::mouseReleased));
             mouseEnteredHandlerList.push_back(MOUSE_DELEGATE(Panel::mouseEntered));
             mouseExitedHandlerList.push_back(MOUSE_DELEGATE(Panel::mouseExited));
+            mouseWheelHandlerList.push_back(MOUSE_DELEGATE(Panel::mouseWheel));
 
 			pack();
 		}
@@ -40,7 +41,7 @@ namespace AssortedWidgets
 			std::vector<Element*>::iterator iter;
 			for(iter=childList.begin();iter<childList.end();++iter)
 			{
-				if((*iter)->isIn(mx,my))
+				if((*iter)->isVisible() && (*iter)->isEnable() && (*iter)->isIn(mx,my))
 				{
 					Event::MouseEvent event((*iter),Event::MouseEvent::MOUSE_ENTERED,mx,my,0);
 					(*iter)->processMouseEntered(event);
@@ -73,7 +74,7 @@ namespace AssortedWidgets
 			std::vector<Element*>::iterator iter;
 			for(iter=childList.begin();iter<childList.end();++iter)
 			{
-				if((*iter)->isIn(mx,my))
+				if((*iter)->isVisible() && (*iter)->isEnable() && (*iter)->isIn(mx,my))
 				{
                     if((*iter)->m_isHover)
 					{
@@ -108,7 +109,7 @@ namespace AssortedWidgets
 			std::vector<Element*>::iterator iter;
 			for(iter=childList.begin();iter<childList.end();++iter)
 			{
-				if((*iter)->isIn(mx,my))
+				if((*iter)->isVisible() && (*iter)->isEnable() && (*iter)->isIn(mx,my))
 				{
 					Event::MouseEvent event((*iter),Event::MouseEvent::MOUSE_PRESSED,mx,my,0);
 					(*iter)->processMousePressed(event);
@@ -117,6 +118,26 @@ namespace AssortedWidgets
 			}
 		}
 
+		void Panel::mouseWheel(const Event::MouseEvent &e)
+		{
+            int mx=e.getX()-m_position.x;
+            int my=e.getY()-m_position.y;
+			std::vector<Element*>::iterator iter;
+			for(iter=childList.begin();iter<childList.end();++iter)
+			{
+				if((*iter)->isVisible() && (*iter)->isEnable() && (*iter)->isIn(mx,my))
+				{
+					Event::MouseEvent event((*iter),Event::MouseEvent::MOUSE_WHEEL,mx,my,e.getButton());
+					(*iter)->processMouseWheel(event);
+					if(event.isConsumed())
+					{
+						e.consume();
+					}
+					break;
+				}
+			}
+		}
+
 		void Panel::mouseReleased(const Event::MouseEvent &e)
 		{
             int mx=e.getX()-m_position.x;
@@ -124,7 +145,7 @@ namespace AssortedWidgets
 			std::vector<Element*>::iterator iter;
 			for(iter=childList.begin();iter<childList.end();++iter)
 			{
-				if((*iter)->isIn(mx,my))
+				if((*iter)->isVisible() && (*iter)->isEnable() && (*iter)->isIn(mx,my))
 				{
 					Event::MouseEvent event((*iter),Event::MouseEvent::MOUSE_RELEASED,mx,my,0);
 					(*iter)->processMouseReleased(event);
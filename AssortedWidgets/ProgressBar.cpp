@@ -9,7 +9,10 @@ namespace AssortedWidgets
               m_value(0.0f),
               m_min(0.0f),
               m_max(100.0f),
-              m_POfSlider(0)
+              m_POfSlider(0),
+              m_indeterminate(false),
+              m_sweepPeriod(1500),
+              m_showLabel(false)
 		{
             m_size=getPreferedSize();
             if(m_type==Horizontal)
@@ -30,7 +33,10 @@ namespace AssortedWidgets
               m_value(0.0f),
               m_min(0.0f),
               m_max(100.0f),
-              m_POfSlider(0)
+              m_POfSlider(0),
+              m_indeterminate(false),
+              m_sweepPeriod(1500),
+              m_showLabel(false)
 		{
             m_size=getPreferedSize();
             if(m_type==Horizontal)
@@ -51,7 +57,10 @@ namespace AssortedWidgets
               m_value(0.0f),
               m_min(_min),
               m_max(_max),
-              m_POfSlider(0)
+              m_POfSlider(0),
+              m_indeterminate(false),
+              m_sweepPeriod(1500),
+              m_showLabel(false)
 		{
             m_size=getPreferedSize();
             if(m_type==Horizontal)
@@ -72,7 +81,10 @@ namespace AssortedWidgets
               m_value(0),
               m_min(_min),
               m_max(_max),
-              m_POfSlider(0)
+              m_POfSlider(0),
+              m_indeterminate(false),
+              m_sweepPeriod(1500),
+              m_showLabel(false)
 		{
 			setValue(_value);
             m_size=getPreferedSize();
@@ -21,6 +21,9 @@ namespace AssortedWidgets
             float m_min;
             float m_max;
             unsigned int m_POfSlider;
+            bool m_indeterminate;
+            unsigned int m_sweepPeriod;
+            bool m_showLabel;
 		public:
             int getType() const
 			{
@@ -32,13 +35,54 @@ namespace AssortedWidgets
                 return m_min+(m_max-m_min)*m_value;
             }
 
+            float getPercent() const
+			{
+                return m_value;
+            }
+
+            bool isShowLabel() const
+			{
+                return m_showLabel;
+            }
+
+			//draws the percentage centered on the bar
+			void setShowLabel(bool _showLabel)
+			{
+                m_showLabel=_showLabel;
+            }
+
             unsigned int getPOfSlider() const
 			{
                 return m_POfSlider;
             }
 
+            bool isIndeterminate() const
+			{
+                return m_indeterminate;
+            }
+
+			void setIndeterminate(bool _indeterminate)
+			{
+                m_indeterminate=_indeterminate;
+            }
+
+            unsigned int getSweepPeriod() const
+			{
+                return m_sweepPeriod;
+            }
+
+			//milliseconds for the highlight to travel across the bar once
+			void setSweepPeriod(unsigned int _sweepPeriod)
+			{
+                if(_sweepPeriod)
+				{
+                    m_sweepPeriod=_sweepPeriod;
+				}
+            }
+
 			void setValue(float _value)
 			{
+                m_indeterminate=false;
                 if(_value>=m_min && _value<=m_max)
 				{
                     m_value=(_value-m_min)/(m_max-m_min);
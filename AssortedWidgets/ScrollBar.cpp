@@ -17,6 +17,8 @@ namespace AssortedWidgets
 			{
                 m_min=new ScrollBarButton(ScrollBarButton::HorizontalLeft);
                 m_max=new ScrollBarButton(ScrollBarButton::HorizontalRight);
+                m_min->setLogicalParent(this);
+                m_max->setLogicalParent(this);
                 m_slider=new ScrollBarSlider(ScrollBarSlider::Horizontal);
                 setHorizontalStyle(Element::Stretch);
                 setVerticalStyle(Element::Fit);
@@ -32,6 +34,8 @@ namespace AssortedWidgets
 			{
                 m_min=new ScrollBarButton(ScrollBarButton::VerticalTop);
                 m_max=new ScrollBarButton(ScrollBarButton::VerticalBottom);
+                m_min->setLogicalParent(this);
+                m_max->setLogicalParent(this);
                 m_slider=new ScrollBarSlider(ScrollBarSlider::Vertical);
 				setHorizontalStyle(Element::Fit);
 				setVerticalStyle(Element::Stretch);
@@ -150,23 +154,17 @@ namespace AssortedWidgets
 
         void ScrollBar::onMinReleased(const Event::MouseEvent &)
 		{
-            m_value=std::max<float>(m_value-0.1f,0.0f);
-            if(m_type==Horizontal)
-			{
-                m_slider->m_position.x=static_cast<int>(((m_size.m_width-34)-m_slider->m_size.m_width)*m_value+17);
-                m_slider->m_position.y=2;
-			}
-            else if(m_type==Vertical)
-			{
-                m_slider->m_position.x=2;
-                m_slider->m_position.y=static_cast<int>(((m_size.m_height-34)-m_slider->m_size.m_height)*m_value+17);
-			}
-			onValueChanged();
+            scroll(-0.1f);
 		}
 
         void ScrollBar::onMaxReleased(const Event::MouseEvent &)
 		{
-            m_value=std::min<float>(m_value+0.1f,1.0f);
+            scroll(0.1f);
+		}
+
+		void ScrollBar::scroll(float delta)
+		{
+            m_value=std::min<float>(std::max<float>(m_value+delta,0.0f),1.0f);
             if(m_type==Horizontal)
 			{
                 m_slider->m_position.x=static_cast<int>(((m_size.m_width-34)-m_slider->m_size.m_width)*m_value+17);
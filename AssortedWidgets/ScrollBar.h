@@ -64,6 +64,7 @@ namespace AssortedWidgets
 			void mouseExited(const Event::MouseEvent &e);
 			void mouseMoved(const Event::MouseEvent &e);
 
+			void scroll(float delta);
 			void onMinReleased(const Event::MouseEvent &e);
 			void onMaxReleased(const Event::MouseEvent &e);
 			void pack();
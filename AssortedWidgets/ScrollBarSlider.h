@@ -24,6 +24,10 @@ namespace AssortedWidgets
 			{
                 return m_type;
 			}
+			ScrollBar* getScrollBar()
+			{
+                return m_parent;
+            }
 			void setScrollBar(ScrollBar *_parent)
 			{
                 m_parent=_parent;
@@ -29,12 +29,15 @@ namespace AssortedWidgets
 			setVerticalStyle(Element::Stretch);
             m_horizontalBar->setScrollPanel(this);
             m_verticalBar->setScrollPanel(this);
+            m_horizontalBar->setLogicalParent(this);
+            m_verticalBar->setLogicalParent(this);
 
             mousePressedHandlerList.push_back(MOUSE_DELEGATE(ScrollPanel::mousePressed));
             mouseReleasedHandlerList.push_back(MOUSE_DELEGATE(ScrollPanel::mouseReleased));
             mouseEnteredHandlerList.push_back(MOUSE_DELEGATE(ScrollPanel::mouseEntered));
             mouseExitedHandlerList.push_back(MOUSE_DELEGATE(ScrollPanel::mouseExited));
             mouseMovedHandlerList.push_back(MOUSE_DELEGATE(ScrollPanel::mouseMoved));
+            mouseWheelHandlerList.push_back(MOUSE_DELEGATE(ScrollPanel::mouseWheel));
 
 			pack();
 		}
@@ -56,6 +59,32 @@ namespace AssortedWidgets
                 m_horizontalBar->processMouseEntered(event);
 				return;			
 			}
+            else if(isInContent(mx,my))
+			{
+                Event::MouseEvent event(m_content,Event::MouseEvent::MOUSE_ENTERED,mx,my,0);
+                m_content->processMouseEntered(event);
+			}
+		}
+
+        bool ScrollPanel::isInContent(int mx,int my)
+		{
+            if(mx<2 || my<2 || mx>static_cast<int>(m_scissorWidth)+2 || my>static_cast<int>(m_scissorHeight)+2)
+			{
+                return false;
+			}
+            return m_content && m_content->isVisible() && m_content->isEnable() && m_content->isIn(mx,my);
+		}
+
+        void ScrollPanel::scrollTo(unsigned int x,unsigned int y)
+		{
+            if(m_horizontalBarShow && m_offsetXMax)
+			{
+                m_horizontalBar->scroll(std::min<float>(static_cast<float>(x)/m_offsetXMax,1.0f)-m_horizontalBar->getValue());
+			}
+            if(m_verticalBarShow && m_offsetYMax)
+			{
+                m_verticalBar->scroll(std::min<float>(static_cast<float>(y)/m_offsetYMax,1.0f)-m_verticalBar->getValue());
+			}
 		}
 
 		void ScrollPanel::onValueChanged(ScrollBar *scrollBar)
@@ -82,6 +111,26 @@ namespace AssortedWidgets
 		{
             int mx=e.getX()-m_position.x;
             int my=e.getY()-m_position.y;
+            if(isInContent(mx,my))
+			{
+                if(m_content->m_isHover)
+				{
+                    Event::MouseEvent event(m_content,Event::MouseEvent::MOUSE_MOTION,mx,my,0);
+                    m_content->processMouseMoved(event);
+				}
+				else
+				{
+                    Event::MouseEvent event(m_content,Event::MouseEvent::MOUSE_ENTERED,mx,my,0);
+                    m_content->processMouseEntered(event);
+				}
+				return;
+			}
+            else if(m_content && m_content->m_isHover)
+			{
+                Event::MouseEvent event(m_content,Event::MouseEvent::MOUSE_EXITED,mx,my,0);
+                m_content->processMouseExited(event);
+			}
+
             if(m_verticalBar->isIn(mx,my))
 			{
                 if(m_verticalBar->m_isHover)
@@ -138,7 +187,13 @@ namespace AssortedWidgets
             m_isHover=false;
             int mx=e.getX()-m_position.x;
             int my=e.getY()-m_position.y;
-            if(m_verticalBar->m_isHover)
+            if(m_content && m_content->m_isHover)
+			{
+                Event::MouseEvent event(m_content,Event::MouseEvent::MOUSE_EXITED,mx,my,0);
+                m_content->processMouseExited(event);
+				return;
+			}
+            else if(m_verticalBar->m_isHover)
 			{
                 Event::MouseEvent event(m_verticalBar,Event::MouseEvent::MOUSE_EXITED,mx,my,0);
                 m_verticalBar->processMouseExited(event);
@@ -168,6 +223,11 @@ namespace AssortedWidgets
                 m_horizontalBar->processMouseReleased(event);
 				return;			
 			}
+            else if(isInContent(mx,my))
+			{
+                Event::MouseEvent event(m_content,Event::MouseEvent::MOUSE_RELEASED,mx,my,0);
+                m_content->processMouseReleased(event);
+			}
 		}
 
 		void ScrollPanel::mousePressed(const Event::MouseEvent &e)
@@ -186,6 +246,38 @@ namespace AssortedWidgets
                 m_horizontalBar->processMousePressed(event);
 				return;			
 			}
+            else if(isInContent(mx,my))
+			{
+                Event::MouseEvent event(m_content,Event::MouseEvent::MOUSE_PRESSED,mx,my,0);
+                m_content->processMousePressed(event);
+			}
+		}
+
+		void ScrollPanel::mouseWheel(const Event::MouseEvent &e)
+		{
+            int mx=e.getX()-m_position.x;
+            int my=e.getY()-m_position.y;
+            if(isInContent(mx,my))
+			{
+                Event::MouseEvent event(m_content,Event::MouseEvent::MOUSE_WHEEL,mx,my,e.getButton());
+                m_content->processMouseWheel(event);
+                if(event.isConsumed())
+				{
+                    e.consume();
+                    return;
+				}
+			}
+            float delta=(e.getButton()==Event::MouseEvent::MOUSE_SCROLL_UP)?-0.1f:0.1f;
+            if(m_verticalBarShow)
+			{
+                m_verticalBar->scroll(delta);
+                e.consume();
+			}
+            else if(m_horizontalBarShow)
+			{
+                m_horizontalBar->scroll(delta);
+                e.consume();
+			}
 		}
 
 		void ScrollPanel::pack()
@@ -250,7 +342,7 @@ namespace AssortedWidgets
 			Util::Position sPosition(2,2);
             Util::Size sArea(m_scissorWidth,m_scissorHeight);
 			Theme::ThemeEngine::getSingleton().getTheme().scissorBegin(sPosition,sArea);
-            if(m_content)
+            if(m_content && m_content->isVisible())
 			{
                 m_content->paint();
 			}
@@ -260,6 +352,10 @@ namespace AssortedWidgets
 
 		ScrollPanel::~ScrollPanel(void)
 		{
+            if(m_content)
+			{
+                m_content->setLogicalParent(0);
+			}
             delete m_horizontalBar;
             delete m_verticalBar;
 		}
@@ -29,7 +29,8 @@ namespace AssortedWidgets
             ScrollBar *m_verticalBar;
             bool m_horizontalBarShow;
             bool m_verticalBarShow;
-			
+
+            bool isInContent(int mx,int my);
 		public:
 			void onValueChanged(ScrollBar *scrollBar);
             bool isHorizontalBarShow() const
@@ -56,6 +57,15 @@ namespace AssortedWidgets
 			{
                 return m_verticalScrollStyle;
 			}
+            //the clipped area the content is shown through
+            Util::Position getViewportPosition() const
+			{
+                return Util::Position(2,2);
+            }
+            Util::Size getViewportSize() const
+			{
+                return Util::Size(m_scissorWidth,m_scissorHeight);
+            }
             unsigned int getOffsetX() const
 			{
                 return m_offsetX;
@@ -67,11 +77,18 @@ namespace AssortedWidgets
 			void setContent(Element *_content)
 			{
                 m_content=_content;
+                m_content->setLogicalParent(this);
                 m_offsetXMax=m_content->m_size.m_width-(m_size.m_width-17);
                 m_offsetYMax=m_content->m_size.m_height-(m_size.m_height-17);
 			}
+            //scrolls so that content pixel (x,y) sits at the top left, as far as the bars allow
+            void scrollTo(unsigned int x,unsigned int y);
 			void removeContent()
 			{
+                if(m_content)
+				{
+                    m_content->setLogicalParent(0);
+				}
                 m_content=0;
 			}
 			ScrollPanel(void);
@@ -87,6 +104,7 @@ namespace AssortedWidgets
 			void mouseExited(const Event::MouseEvent &e);
 
 			void mouseMoved(const Event::MouseEvent &e);
+			void mouseWheel(const Event::MouseEvent &e);
 
 			void pack();
 		public:
@@ -0,0 +1,93 @@
+#include "ShortcutManager.h"
+#include "KeyEvent.h"
+#include "TypeActiveManager.h"
+#include "TypeAble.h"
+
+namespace AssortedWidgets
+{
+	namespace Manager
+	{
+		ShortcutManager::~ShortcutManager(void)
+		{
+		}
+
+        int ShortcutManager::normalize(int modifier)
+        {
+            int result=None;
+            if(modifier & (Event::KeyEvent::MOD_LSHIFT|Event::KeyEvent::MOD_RSHIFT))
+            {
+                result|=Shift;
+            }
+            if(modifier & (Event::KeyEvent::MOD_LCTRL|Event::KeyEvent::MOD_RCTRL))
+            {
+                result|=Ctrl;
+            }
+            if(modifier & (Event::KeyEvent::MOD_LALT|Event::KeyEvent::MOD_RALT))
+            {
+                result|=Alt;
+            }
+            if(modifier & (Event::KeyEvent::MOD_LMETA|Event::KeyEvent::MOD_RMETA))
+            {
+                result|=Meta;
+            }
+            return result;
+        }
+
+		void ShortcutManager::registerShortcut(int keyCode,int modifier,ShortcutDelegate _delegate,bool _whileTyping)
+		{
+            unregisterShortcut(keyCode,modifier);
+            Shortcut shortcut;
+            shortcut.m_keyCode=keyCode;
+            shortcut.m_modifier=modifier;
+            shortcut.m_whileTyping=_whileTyping;
+            shortcut.m_delegate=_delegate;
+            m_shortcuts.push_back(shortcut);
+		}
+
+		void ShortcutManager::unregisterShortcut(int keyCode,int modifier)
+		{
+            std::vector<Shortcut>::iterator iter;
+            for(iter=m_shortcuts.begin();iter<m_shortcuts.end();++iter)
+			{
+                if((*iter).m_keyCode==keyCode && (*iter).m_modifier==modifier)
+				{
+                    m_shortcuts.erase(iter);
+					return;
+				}
+			}
+		}
+
+		bool ShortcutManager::isRegistered(int keyCode,int modifier) const
+		{
+            std::vector<Shortcut>::const_iterator iter;
+            for(iter=m_shortcuts.begin();iter<m_shortcuts.end();++iter)
+			{
+                if((*iter).m_keyCode==keyCode && (*iter).m_modifier==modifier)
+				{
+					return true;
+				}
+			}
+            return false;
+		}
+
+		bool ShortcutManager::processKeyDown(int keyCode,int modifier)
+		{
+            int pressed=normalize(modifier);
+            Widgets::TypeAble *typing=TypeActiveManager::getSingleton().getActive();
+            std::vector<Shortcut>::iterator iter;
+            for(iter=m_shortcuts.begin();iter<m_shortcuts.end();++iter)
+			{
+                if((*iter).m_keyCode==keyCode && (*iter).m_modifier==pressed)
+				{
+                    if(typing && (!(*iter).m_whileTyping || !(pressed & ~Shift) || typing->isShortcutReserved(keyCode,pressed)))
+					{
+                        return false;
+					}
+                    (*iter).m_delegate();
+					return true;
+				}
+			}
+            return false;
+		}
+	}
+}
@@ -0,0 +1,64 @@
+#pragma once
+#include <vector>
+#include <functional>
+
+namespace AssortedWidgets
+{
+	namespace Manager
+	{
+		class ShortcutManager
+		{
+		public:
+            typedef std::function<void()> ShortcutDelegate;
+
+			enum Modifier
+			{
+				None  = 0x0,
+				Shift = 0x1,
+				Ctrl  = 0x2,
+				Alt   = 0x4,
+				Meta  = 0x8
+			};
+
+		private:
+			struct Shortcut
+			{
+                int m_keyCode;
+                int m_modifier;
+                bool m_whileTyping;
+                ShortcutDelegate m_delegate;
+			};
+
+            std::vector<Shortcut> m_shortcuts;
+
+            ShortcutManager(void)
+            {}
+            ~ShortcutManager(void);
+
+            static int normalize(int modifier);
+		public:
+			static ShortcutManager& getSingleton()
+			{
+				static ShortcutManager obj;
+				return obj;
+			}
+
+			//Cmd on Mac, Ctrl elsewhere
+			static int primary()
+			{
+#ifdef __APPLE__
+                return Meta;
+#else
+                return Ctrl;
+#endif
+			}
+
+			//_whileTyping=false lets an active text field keep the key combination for itself,
+			//keys without Ctrl, Alt or Meta and combinations reserved by the field always go to it
+			void registerShortcut(int keyCode,int modifier,ShortcutDelegate _delegate,bool _whileTyping=true);
+			void unregisterShortcut(int keyCode,int modifier);
+			bool isRegistered(int keyCode,int modifier) const;
+			bool processKeyDown(int keyCode,int modifier);
+		};
+	}
+}
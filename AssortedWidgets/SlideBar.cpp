@@ -3,6 +3,7 @@
 #include "SlideBarSlider.h"
 #include "ThemeEngine.h"
 #include "Graphics.h"
+#include <cmath>
 
 namespace AssortedWidgets
 {
@@ -12,7 +13,9 @@ namespace AssortedWidgets
             :m_type(_type),
               m_value(0.0f),
               m_minV(0.0f),
-              m_maxV(100.0f)
+              m_maxV(100.0f),
+              m_step(0.0f),
+              m_tickCount(0)
 		{
             if(m_type==Horizontal)
 			{
@@ -48,7 +51,9 @@ namespace AssortedWidgets
             : m_type(_type),
               m_value(0),
               m_minV(_minV),
-              m_maxV(_maxV)
+              m_maxV(_maxV),
+              m_step(0.0f),
+              m_tickCount(0)
 		{
             if(m_type==Horizontal)
 			{
@@ -81,9 +86,8 @@ namespace AssortedWidgets
 		}
 
         SlideBar::SlideBar(float _minV,float _maxV,float _value,int _type)
-            :m_type(_type),m_value(0),m_minV(_minV),m_maxV(_maxV)
+            :m_type(_type),m_value(0),m_minV(_minV),m_maxV(_maxV),m_step(0.0f),m_tickCount(0)
 		{
-			setValue(_value);
             if(m_type==Horizontal)
 			{
                 m_slider=new SlideBarSlider(SlideBarSlider::Horizontal);
@@ -110,6 +114,7 @@ namespace AssortedWidgets
                 m_slider->m_position.y=static_cast<int>(((m_size.m_height-4)-m_slider->m_size.m_height)*m_value+2);
                 m_slider->setSlideBar(this);
 			}
+			setValue(_value);
 
             mousePressedHandlerList.push_back(MOUSE_DELEGATE(SlideBar::mousePressed));
 		}
@@ -126,6 +131,47 @@ namespace AssortedWidgets
 			}
 		}
 
+		void SlideBar::setPercent(float _value)
+		{
+            m_value=std::min<float>(std::max<float>(_value,0.0f),1.0f);
+            if(m_step>0.0f && m_maxV>m_minV)
+			{
+                float range=m_maxV-m_minV;
+                float snapped=std::floor(m_value*range/m_step+0.5f)*m_step;
+                m_value=std::min<float>(snapped/range,1.0f);
+			}
+            updateSliderPosition();
+		}
+
+		void SlideBar::setValue(float _value)
+		{
+            if(_value>=m_minV && _value<=m_maxV)
+			{
+                setPercent((_value-m_minV)/(m_maxV-m_minV));
+			}
+		}
+
+		void SlideBar::updateSliderPosition()
+		{
+            if(m_type==Horizontal)
+			{
+                m_slider->m_position.x=static_cast<int>(((m_size.m_width-4)-m_slider->m_size.m_width)*m_value+2.5f);
+			}
+            else if(m_type==Vertical)
+			{
+                m_slider->m_position.y=static_cast<int>(((m_size.m_height-4)-m_slider->m_size.m_height)*m_value+2.5f);
+			}
+		}
+
+		unsigned int SlideBar::getSliderLength() const
+		{
+            if(m_type==Horizontal)
+			{
+                return m_slider->m_size.m_width;
+			}
+            return m_slider->m_size.m_height;
+		}
+
 		void SlideBar::paint()
 		{
 			Theme::ThemeEngine::getSingleton().getTheme().paintSlideBar(this);
@@ -22,7 +22,10 @@ namespace AssortedWidgets
             float m_value;
             float m_minV;
             float m_maxV;
+            float m_step;
+            unsigned int m_tickCount;
 
+            void updateSliderPosition();
 		public:
             float getValue() const
 			{
@@ -33,17 +36,27 @@ namespace AssortedWidgets
                 return m_maxV;
             }
 			//void onValueChanged();
-			void setValue(float _value)
+			void setValue(float _value);
+			void setPercent(float _value);
+            float getStep() const
 			{
-                if(_value>=m_minV && _value<=m_maxV)
-				{
-                    m_value=(_value-m_minV)/(m_maxV-m_minV);
-				}
+                return m_step;
+            }
+			//0 keeps the slider continuous, otherwise values snap to multiples of _step from the minimum
+			void setStep(float _step)
+			{
+                m_step=_step;
+                setPercent(m_value);
+            }
+            unsigned int getTickCount() const
+			{
+                return m_tickCount;
             }
-			void setPercent(float _value)
+			void setTickCount(unsigned int _tickCount)
 			{
-                m_value=_value;
+                m_tickCount=_tickCount;
             }
+            unsigned int getSliderLength() const;
 			SlideBar(int _type=Horizontal);
 			SlideBar(float _minV,float _maxV,int _type=Horizontal);
 			SlideBar(float _minV,float _maxV,float _value,int _type=Horizontal);
@@ -5,8 +5,11 @@ namespace AssortedWidgets
 	namespace Widgets
 	{
         SlideBarSlider::SlideBarSlider(int _type)
-            :m_type(_type)
+            :m_parent(0),
+              m_type(_type),
+              m_dragPosition(0)
 		{
+            mousePressedHandlerList.push_back(MOUSE_DELEGATE(SlideBarSlider::onPressed));
 		}
 
 		SlideBarSlider::~SlideBarSlider(void)
@@ -19,11 +19,16 @@ namespace AssortedWidgets
 			};
 		private:
             int m_type;
+            int m_dragPosition;
 		public:
             int getType() const
 			{
                 return m_type;
 			}
+			SlideBar* getSlideBar()
+			{
+                return m_parent;
+            }
 			void setSlideBar(SlideBar *_parent)
 			{
                 m_parent=_parent;
@@ -42,11 +47,17 @@ namespace AssortedWidgets
             void dragReleased(const Event::MouseEvent &)
             {}
 
+			void onPressed(const Event::MouseEvent &)
+			{
+                m_dragPosition=(m_type==Horizontal)?m_position.x:m_position.y;
+            }
+
 			void dragMoved(int offsetX,int offsetY)
 			{
                 if(m_type==Horizontal)
 				{
-                    m_position.x+=offsetX;
+                    m_dragPosition+=offsetX;
+                    m_position.x=m_dragPosition;
                     if(m_position.x<2)
 					{
                         m_position.x=2;
@@ -59,7 +70,8 @@ namespace AssortedWidgets
 				}
                 else if(m_type==Vertical)
 				{
-                    m_position.y+=offsetY;
+                    m_dragPosition+=offsetY;
+                    m_position.y=m_dragPosition;
                     if(m_position.y<2)
 					{
                         m_position.y=2;
@@ -34,6 +34,7 @@ namespace AssortedWidgets
 		class DropListButton;
 		class DropList;
 		class DropListItem;
+		class Badge;
 	}
 
 	namespace Theme
@@ -104,7 +105,10 @@ namespace AssortedWidgets
 
 			virtual Util::Size getDropListItemPreferedSize(Widgets::DropListItem *component)=0;
 			virtual void paintDropListItem(Widgets::DropListItem *component)=0;
+			virtual Util::Size getBadgePreferedSize(Widgets::Badge *component)=0;
+			virtual void paintBadge(Widgets::Badge *component)=0;
 			virtual void paintDropDown(Util::Position &position,Util::Size &area)=0;
+			virtual void paintModalScrim()=0;
 			virtual void scissorBegin(Util::Position &position,Util::Size &area)=0;
 			virtual void scissorEnd()=0;
 		};
@@ -24,7 +24,9 @@ namespace AssortedWidgets
                //printf("Can't load font\n");
                    // return 0;
             }
+            m_font = m_fontNormal;
             m_size = _size;
+            m_letterSpacing = 0.0f;
 		}
 
         Util::Size TrueTypeFont::getStringBoundingBox(const std::string &text)
@@ -44,7 +46,9 @@ namespace AssortedWidgets
 
             glfonsGenText(m_stash, 1, &textID);
 
+            fonsSetFont(m_stash, m_font);
             fonsSetSize(m_stash, m_size);
+            fonsSetSpacing(m_stash, m_letterSpacing);
             glfonsRasterize(m_stash, textID, text.c_str());
             glfonsGetBBox(m_stash,  textID, &minx, &miny, &maxx, &maxy);
             glfonsBufferDelete(m_stash, buffer);
@@ -80,7 +84,7 @@ namespace AssortedWidgets
            // unsigned int brown = glfonsRGBA(192,128,0,128);
             fsuint textID = 0;
             fsuint buffer;
-            fonsSetFont(m_stash, m_fontNormal);
+            fonsSetFont(m_stash, m_font);
            // fonsSetSize(m_stash, 124.0f);
            // fonsSetColor(m_stash, white);
 
@@ -91,6 +95,7 @@ namespace AssortedWidgets
             glfonsSetColor(m_stash, m_color);
 
             fonsSetSize(m_stash, m_size);
+            fonsSetSpacing(m_stash, m_letterSpacing);
             glfonsRasterize(m_stash, textID, text.c_str());
             glfonsTransform(m_stash, textID, x, y+9, 0.0, 1.0);
             glfonsUpdateBuffer(m_stash);
@@ -145,11 +150,41 @@ namespace AssortedWidgets
 
         }
 
+        int TrueTypeFont::addFont(const std::string &name, const char *path)
+        {
+            return fonsAddFont(m_stash, name.c_str(), path);
+        }
+
+        int TrueTypeFont::addFontMemory(const std::string &name, unsigned char *data, int dataSize)
+        {
+            return fonsAddFontMem(m_stash, name.c_str(), data, dataSize, 0);
+        }
+
+        void TrueTypeFont::setFontFamily(const std::string &name)
+        {
+            m_font = fonsGetFontByName(m_stash, name.c_str());
+            if (m_font == FONS_INVALID)
+            {
+                m_font = m_fontNormal;
+            }
+        }
+
         void TrueTypeFont::setScreenSize(unsigned int width, unsigned int height)
         {
             m_width = width;
             m_height = height;
         }
 
+        unsigned int TrueTypeFont::getAtlasTexture() const
+        {
+            GLFONScontext* gl = (GLFONScontext*) m_stash->params.userPtr;
+            return gl->atlas;
+        }
+
+        void TrueTypeFont::getAtlasSize(int &width, int &height) const
+        {
+            fonsGetAtlasSize(m_stash, &width, &height);
+        }
+
 	}
 }
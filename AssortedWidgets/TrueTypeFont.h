@@ -16,6 +16,7 @@ namespace AssortedWidgets
             struct FONScontext* m_stash;
             int m_font;
             int m_size;
+            float m_letterSpacing;
             unsigned int  m_textBuffer;
             int m_fontNormal;
             unsigned int m_color;
@@ -37,8 +38,28 @@ namespace AssortedWidgets
 
             void setColor(int r, int g, int b);
 
+            int addFont(const std::string &name, const char *path);
+
+            int addFontMemory(const std::string &name, unsigned char *data, int dataSize);
+
+            void setFontFamily(const std::string &name);
+
+            void setLetterSpacing(float spacing)
+            {
+                m_letterSpacing = spacing;
+            }
+
+            float getLetterSpacing() const
+            {
+                return m_letterSpacing;
+            }
+
             void setScreenSize(unsigned int width, unsigned int height);
 
+            unsigned int getAtlasTexture() const;
+
+            void getAtlasSize(int &width, int &height) const;
+
 		public:
             ~TrueTypeFont(void);
 		};
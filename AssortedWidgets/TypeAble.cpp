@@ -1,13 +1,59 @@
 #include "TypeAble.h"
 #include "TypeActiveManager.h"
+#include "ShortcutManager.h"
+#include <SDL.h>
+#include <algorithm>
 
 namespace AssortedWidgets
 {
 	namespace Widgets
 	{
-        TypeAble::TypeAble(const std::string &_text):m_text(_text),m_active(false)
+        //counts UTF-8 code points by skipping continuation bytes
+        static size_t utf8Length(const std::string &text)
+        {
+            size_t length=0;
+            for(size_t i=0;i<text.length();++i)
+            {
+                if((static_cast<unsigned char>(text[i]) & 0xC0)!=0x80)
+                {
+                    ++length;
+                }
+            }
+            return length;
+        }
+
+        //byte offset just past the first count code points
+        static size_t utf8Offset(const std::string &text,size_t count)
+        {
+            size_t i=0;
+            while(i<text.length())
+            {
+                if((static_cast<unsigned char>(text[i]) & 0xC0)!=0x80)
+                {
+                    if(count==0)
+                    {
+                        break;
+                    }
+                    --count;
+                }
+                ++i;
+            }
+            return i;
+        }
+
+        TypeAble::TypeAble(const std::string &_text):m_text(_text),m_active(false),m_blinkStart(0),m_maxLength(0)
         {
             mousePressedHandlerList.push_back(MOUSE_DELEGATE(TypeAble::mousePressed));
+
+            //clipboard keys and word deletion belong to the text field
+            const char clipboardKeys[]={'c','x','v'};
+            for(size_t i=0;i<sizeof(clipboardKeys);++i)
+            {
+                reserveShortcut(clipboardKeys[i],Manager::ShortcutManager::Ctrl);
+                reserveShortcut(clipboardKeys[i],Manager::ShortcutManager::Meta);
+            }
+            reserveShortcut(8,Manager::ShortcutManager::Ctrl);
+            reserveShortcut(8,Manager::ShortcutManager::Alt);
 		}
 
 		TypeAble::~TypeAble(void)
@@ -19,15 +65,99 @@ namespace AssortedWidgets
             (void) e;
 			Manager::TypeActiveManager::getSingleton().setActive(this);
             m_active=true;
+            resetBlink();
 		}
 
-        void TypeAble::onCharTyped(char character,int modifier)
+        void TypeAble::resetBlink()
+        {
+            m_blinkStart=SDL_GetTicks();
+        }
+
+        void TypeAble::setText(const std::string &_text)
+        {
+            if(m_maxLength)
+            {
+                m_text=_text.substr(0,utf8Offset(_text,m_maxLength));
+            }
+            else
+            {
+                m_text=_text;
+            }
+            resetBlink();
+        }
+
+        void TypeAble::setMaxLength(unsigned int _maxLength)
+        {
+            m_maxLength=_maxLength;
+            if(m_maxLength)
+            {
+                m_text.erase(utf8Offset(m_text,m_maxLength));
+            }
+        }
+
+        void TypeAble::appendText(const std::string &_text)
         {
-            if(character==8 && m_text.length())
+            size_t length=utf8Length(m_text);
+            if(m_maxLength && length+utf8Length(_text)>m_maxLength)
             {
-                m_text.erase(m_text.length()-1);
+                m_text+=_text.substr(0,utf8Offset(_text,m_maxLength-std::min<size_t>(length,m_maxLength)));
             }
             else
+            {
+                m_text+=_text;
+            }
+            resetBlink();
+        }
+
+        void TypeAble::reserveShortcut(int keyCode,int modifier)
+        {
+            if(!isShortcutReserved(keyCode,modifier))
+            {
+                m_reservedShortcuts.push_back(std::make_pair(keyCode,modifier));
+            }
+        }
+
+        void TypeAble::unreserveShortcut(int keyCode,int modifier)
+        {
+            m_reservedShortcuts.erase(std::remove(m_reservedShortcuts.begin(),m_reservedShortcuts.end(),std::make_pair(keyCode,modifier)),m_reservedShortcuts.end());
+        }
+
+        bool TypeAble::isShortcutReserved(int keyCode,int modifier) const
+        {
+            return std::find(m_reservedShortcuts.begin(),m_reservedShortcuts.end(),std::make_pair(keyCode,modifier))!=m_reservedShortcuts.end();
+        }
+
+        void TypeAble::removeLastWord()
+        {
+            size_t end=m_text.length();
+            while(end>0 && isspace(static_cast<unsigned char>(m_text[end-1])))
+            {
+                --end;
+            }
+            while(end>0 && !isspace(static_cast<unsigned char>(m_text[end-1])))
+            {
+                --end;
+            }
+            m_text.erase(end);
+        }
+
+        void TypeAble::onCharTyped(char character,int modifier)
+        {
+            resetBlink();
+            if(character==8 && (modifier & (Event::KeyEvent::MOD_LCTRL|Event::KeyEvent::MOD_RCTRL|Event::KeyEvent::MOD_LALT|Event::KeyEvent::MOD_RALT)))
+            {
+                removeLastWord();
+            }
+            else if(character==8 && m_text.length())
+            {
+                size_t end=m_text.length()-1;
+                while(end>0 && (static_cast<unsigned char>(m_text[end]) & 0xC0)==0x80)
+                {
+                    --end;
+                }
+                m_text.erase(end);
+            }
+            else if(m_maxLength==0 || utf8Length(m_text)<m_maxLength)
             {
                 if((modifier & Event::KeyEvent::MOD_LSHIFT) ||(modifier & Event::KeyEvent::MOD_RSHIFT) ||(modifier & Event::KeyEvent::MOD_CAPS))
                 {
@@ -3,6 +3,8 @@
 #include "KeyEvent.h"
 #include <ctype.h>
 #include <string>
+#include <vector>
+#include <utility>
 
 namespace AssortedWidgets
 {
@@ -13,6 +15,9 @@ namespace AssortedWidgets
 		private:
             std::string m_text;
             bool m_active;
+            unsigned int m_blinkStart;
+            unsigned int m_maxLength;
+            std::vector<std::pair<int,int> > m_reservedShortcuts;
 		public:
             TypeAble(const std::string &_text = std::string());
 			bool isActive()
@@ -23,12 +28,31 @@ namespace AssortedWidgets
 			{
                 return m_text;
 			}
+            //both setters cut the text down to the maximum length
+            void setText(const std::string &_text);
+            void setMaxLength(unsigned int _maxLength);
+            unsigned int getMaxLength() const
+            {
+                return m_maxLength;
+            }
+            void appendText(const std::string &_text);
+            //modifier uses ShortcutManager::Modifier flags; a reserved combination never triggers a shortcut while this is active
+            void reserveShortcut(int keyCode,int modifier);
+            void unreserveShortcut(int keyCode,int modifier);
+            bool isShortcutReserved(int keyCode,int modifier) const;
 			void setActive(bool _active)
 			{
                 m_active=_active;
+                resetBlink();
+            }
+            unsigned int getBlinkStart() const
+            {
+                return m_blinkStart;
             }
+            void resetBlink();
 			void mousePressed(const Event::MouseEvent &e);
             void onCharTyped(char character,int modifier);
+            void removeLastWord();
 
 		public:
 			~TypeAble(void);
@@ -1,5 +1,7 @@
 #include "TypeActiveManager.h"
 #include "TypeAble.h"
+#include "KeyEvent.h"
+#include <SDL.h>
 
 namespace AssortedWidgets
 {
@@ -23,14 +25,75 @@ namespace AssortedWidgets
 			}
         }
 
+		Widgets::TypeAble* TypeActiveManager::getActive()
+		{
+            if(m_currentActive && (!m_currentActive->isVisibleInTree() || !m_currentActive->isEnableInTree()))
+			{
+                disactive();
+			}
+            return m_currentActive;
+        }
+
 		void TypeActiveManager::onCharTyped(char character,int modifier)
 		{
-            if(m_currentActive)
+            if(getActive())
 			{
-                m_currentActive->onCharTyped(character,modifier);
+                if(!(modifier & (Event::KeyEvent::MOD_LCTRL|Event::KeyEvent::MOD_RCTRL|Event::KeyEvent::MOD_LMETA|Event::KeyEvent::MOD_RMETA))
+                        || !onClipboardKey(character))
+                {
+                    m_currentActive->onCharTyped(character,modifier);
+                }
 			}
         }
 
+        bool TypeActiveManager::onClipboardKey(char character)
+        {
+            switch(character)
+            {
+                //there is no selection, so copy and cut work on the whole text
+                case 'c':
+                {
+                    SDL_SetClipboardText(m_currentActive->getText().c_str());
+                    return true;
+                }
+                case 'x':
+                {
+                    if(SDL_SetClipboardText(m_currentActive->getText().c_str())==0)
+                    {
+                        m_currentActive->setText(std::string());
+                    }
+                    return true;
+                }
+                case 'v':
+                {
+                    char *clipboard=SDL_GetClipboardText();
+                    if(clipboard)
+                    {
+                        std::string text;
+                        for(const char *c=clipboard;*c;++c)
+                        {
+                            if(*c=='\r' && c[1]=='\n')
+                            {
+                                continue;
+                            }
+                            else if(*c=='\r' || *c=='\n')
+                            {
+                                text+=' ';
+                            }
+                            else if(static_cast<unsigned char>(*c)>=32 && *c!=127)
+                            {
+                                text+=*c;
+                            }
+                        }
+                        SDL_free(clipboard);
+                        m_currentActive->appendText(text);
+                    }
+                    return true;
+                }
+            }
+            return false;
+        }
+
 		TypeActiveManager::~TypeActiveManager(void)
 		{
 		}
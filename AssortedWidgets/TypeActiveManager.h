@@ -20,9 +20,12 @@ namespace AssortedWidgets
 		public:
 			void setActive(Widgets::TypeAble *_currentActive);
 			void onCharTyped(char character,int modifier);
+			bool onClipboardKey(char character);
+			//a hidden or disabled field can no longer take input and is dropped first
+			Widgets::TypeAble* getActive();
 			bool isActive()
 			{
-                return m_currentActive!=0;
+                return getActive()!=0;
             }
 			void disactive();
 			static TypeActiveManager& getSingleton()
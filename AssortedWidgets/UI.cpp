@@ -9,6 +9,8 @@
 namespace AssortedWidgets
 {
 	UI::UI(void)
+		:mouseX(0),
+		mouseY(0)
 	{
 	}
 
@@ -28,14 +30,26 @@ namespace AssortedWidgets
         std::vector<Widgets::Component*>::iterator iter;
 		for(iter=componentList.begin();iter<componentList.end();++iter)
 		{
-			(*iter)->paint();
+			if((*iter)->isVisible())
+			{
+				(*iter)->paint();
+			}
+		}
+		//the menu bar goes under the scrim while a modal dialog is up
+		bool modal=Manager::DialogManager::getSingleton().hasModalDialog();
+		if(modal)
+		{
+			Widgets::MenuBar::getSingleton().paint();
 		}
 		Manager::DialogManager::getSingleton().paint();
 		if(Manager::DropListManager::getSingleton().isDropped())
 		{
 			Manager::DropListManager::getSingleton().paint();
         }
-        Widgets::MenuBar::getSingleton().paint();
+		if(!modal)
+		{
+			Widgets::MenuBar::getSingleton().paint();
+		}
 		end2D();
 	}
 
@@ -31,8 +31,11 @@
 #include "ProgressBar.h"
 #include "SlideBar.h"
 #include "DropList.h"
+#include "Canvas.h"
+#include "Badge.h"
 #include "DropListManager.h"
 #include "DialogManager.h"
+#include "ShortcutManager.h"
 #include "../demo/LabelNButtonTestDialog.h"
 #include "../demo/CheckNRadioTestDialog.h"
 #include "../demo/ProgressNSliderTestDialog.h"
@@ -56,6 +59,8 @@ namespace AssortedWidgets
 		int width;
 		int height;
 		int pressed;
+		int mouseX;
+		int mouseY;
 
 		Widgets::Menu *menuFile;
 		Widgets::Menu *menuEdit;
@@ -150,8 +155,25 @@ namespace AssortedWidgets
 	public:
 		void paint();
 
+		//draws the glyph atlas of the font engine, call it after paint()
+		void debugDrawAtlas(int x,int y,unsigned int _width,unsigned int _height)
+		{
+			GLuint atlas=Font::FontEngine::getSingleton().getFont().getAtlasTexture();
+			float x1=static_cast<float>(x);
+			float y1=static_cast<float>(y);
+			float x2=static_cast<float>(x+_width);
+			float y2=static_cast<float>(y+_height);
+			GraphicsBackend::getSingleton().drawSolidQuad(x1,y1,x2,y2,255,255,255);
+			GraphicsBackend::getSingleton().drawTexturedQuad(x1,y1,x2,y2,0.0f,0.0f,1.0f,1.0f,atlas);
+		}
+
 		void importKeyDown(int keyCode,int modifier)
 		{
+			if(!Manager::DialogManager::getSingleton().hasModalDialog()
+					&& Manager::ShortcutManager::getSingleton().processKeyDown(keyCode,modifier))
+			{
+				return;
+			}
 			if(Manager::TypeActiveManager::getSingleton().isActive())
 			{
 				Manager::TypeActiveManager::getSingleton().onCharTyped(static_cast<char>(keyCode),modifier);
@@ -184,14 +206,15 @@ namespace AssortedWidgets
 			{
 				Manager::TypeActiveManager::getSingleton().disactive();
 			}
-			if(Widgets::MenuBar::getSingleton().isIn(x,y))
+			bool modal=Manager::DialogManager::getSingleton().hasModalDialog();
+			if(!modal && Widgets::MenuBar::getSingleton().isIn(x,y))
 			{
 				Event::MouseEvent event(0,Event::MouseEvent::MOUSE_PRESSED,x,y,button);
 				Widgets::MenuBar::getSingleton().processMousePressed(event);
 			}
 			else
 			{
-				if(Widgets::MenuBar::getSingleton().isExpand())
+				if(!modal && Widgets::MenuBar::getSingleton().isExpand())
 				{
 					Event::MouseEvent event(0,Event::MouseEvent::MOUSE_PRESSED,x,y,button);
 					Widgets::MenuBar::getSingleton().processMousePressed(event);
@@ -200,7 +223,7 @@ namespace AssortedWidgets
 
 			Manager::DialogManager::getSingleton().importMousePressed(x,y);
 
-			if(!componentList.empty())
+			if(!componentList.empty() && !Manager::DialogManager::getSingleton().hasModalDialog())
 			{
 				//std::vector<Widgets::Element*> &hittedComponent=selectionManager.getHitComponents(x,y);
 				//std::vector<Widgets::Element*>::iterator iter;
@@ -208,7 +231,7 @@ namespace AssortedWidgets
 				//for(iter=hittedComponent.begin();iter<hittedComponent.end();++iter)
 				for(iter=componentList.begin();iter<componentList.end();++iter)
 				{
-					if((*iter)->isIn(x,y))
+					if((*iter)->isVisible() && (*iter)->isEnable() && (*iter)->isIn(x,y))
 					{
 						Event::MouseEvent event(0,Event::MouseEvent::MOUSE_PRESSED,x,y,button);
 						(*iter)->processMousePressed(event);
@@ -226,14 +249,15 @@ namespace AssortedWidgets
 				Manager::DragManager::getSingleton().dragEnd();
 			};
 			pressed=false;
-			if(Widgets::MenuBar::getSingleton().isIn(x,y))
+			bool modal=Manager::DialogManager::getSingleton().hasModalDialog();
+			if(!modal && Widgets::MenuBar::getSingleton().isIn(x,y))
 			{
 				Event::MouseEvent event(0,Event::MouseEvent::MOUSE_RELEASED,x,y,button);
 				Widgets::MenuBar::getSingleton().processMouseReleased(event);
 			}
 			else
 			{
-				if(Widgets::MenuBar::getSingleton().isExpand())
+				if(!modal && Widgets::MenuBar::getSingleton().isExpand())
 				{
 					Event::MouseEvent event(0,Event::MouseEvent::MOUSE_RELEASED,x,y,button);
 					Widgets::MenuBar::getSingleton().processMouseReleased(event);
@@ -242,7 +266,7 @@ namespace AssortedWidgets
 
 			Manager::DialogManager::getSingleton().importMouseReleased(x,y);
 
-			if(!componentList.empty())
+			if(!componentList.empty() && !modal)
 			{
 //				std::vector<Widgets::Element*> &hittedComponent=selectionManager.getHitComponents(x,y);
 //				std::vector<Widgets::Element*>::iterator iter;
@@ -250,7 +274,7 @@ namespace AssortedWidgets
 				//for(iter=hittedComponent.begin();iter<hittedComponent.end();++iter)
 				for(iter=componentList.begin();iter<componentList.end();++iter)
 				{
-					if((*iter)->isIn(x,y))
+					if((*iter)->isVisible() && (*iter)->isEnable() && (*iter)->isIn(x,y))
 					{
 						Event::MouseEvent event(0,Event::MouseEvent::MOUSE_RELEASED,x,y,button);
 						(*iter)->processMouseReleased(event);
@@ -260,6 +284,31 @@ namespace AssortedWidgets
 			}
         }
 
+		void importMouseWheel(int delta)
+		{
+			if(delta==0)
+			{
+				return;
+			}
+			int button=delta>0?Event::MouseEvent::MOUSE_SCROLL_UP:Event::MouseEvent::MOUSE_SCROLL_DOWN;
+			if(Manager::DialogManager::getSingleton().importMouseWheel(mouseX,mouseY,button)
+					|| Manager::DialogManager::getSingleton().hasModalDialog())
+			{
+				return;
+			}
+
+			std::vector<Widgets::Component*>::iterator iter;
+			for(iter=componentList.begin();iter<componentList.end();++iter)
+			{
+				if((*iter)->isVisible() && (*iter)->isEnable() && (*iter)->isIn(mouseX,mouseY))
+				{
+					Event::MouseEvent event(0,Event::MouseEvent::MOUSE_WHEEL,mouseX,mouseY,button);
+					(*iter)->processMouseWheel(event);
+					break;
+				}
+			}
+		}
+
 		void init(int _width,int _height)
 		{
 			width=_width;
@@ -597,6 +646,8 @@ namespace AssortedWidgets
 
 		void mouseMotion(int mx,int my)
 		{
+			mouseX=mx;
+			mouseY=my;
 			if(pressed && Manager::DragManager::getSingleton().isOnDrag())
 			{
 				Manager::DragManager::getSingleton().processDrag(mx,my);
@@ -627,7 +678,15 @@ namespace AssortedWidgets
 					}
 				}
 			}
-			if(Widgets::MenuBar::getSingleton().isIn(mx,my))
+			if(Manager::DialogManager::getSingleton().hasModalDialog())
+			{
+                if(Widgets::MenuBar::getSingleton().m_isHover)
+				{
+					Event::MouseEvent event(0,Event::MouseEvent::MOUSE_EXITED,mx,my,0);
+					Widgets::MenuBar::getSingleton().processMouseExited(event);
+				}
+			}
+			else if(Widgets::MenuBar::getSingleton().isIn(mx,my))
 			{
                 if(Widgets::MenuBar::getSingleton().m_isHover)
 				{
@@ -656,7 +715,7 @@ namespace AssortedWidgets
 
 			Manager::DialogManager::getSingleton().importMouseMotion(mx,my);
 
-			if(!componentList.empty())
+			if(!componentList.empty() && !Manager::DialogManager::getSingleton().hasModalDialog())
 			{
 //				std::vector<Widgets::Element*> &hittedComponent=selectionManager.getHitComponents(mx,my);
 //				std::vector<Widgets::Element*>::iterator iter;
@@ -664,7 +723,7 @@ namespace AssortedWidgets
 				//for(iter=hittedComponent.begin();iter<hittedComponent.end();++iter)
 				for(iter=componentList.begin();iter<componentList.end();++iter)
 				{
-					if((*iter)->isIn(mx,my))
+					if((*iter)->isVisible() && (*iter)->isEnable() && (*iter)->isIn(mx,my))
 					{
                         if((*iter)->m_isHover)
 						{
@@ -687,6 +746,19 @@ namespace AssortedWidgets
 					}
 				}
 			}
+			else if(Manager::DialogManager::getSingleton().hasModalDialog())
+			{
+				//nothing behind a modal dialog stays hovered
+				std::vector<Widgets::Component*>::iterator iter;
+				for(iter=componentList.begin();iter<componentList.end();++iter)
+				{
+                    if((*iter)->m_isHover)
+					{
+						Event::MouseEvent event((*iter),Event::MouseEvent::MOUSE_EXITED,mx,my,0);
+						(*iter)->processMouseExited(event);
+					}
+				}
+			}
         }
 	private:	
 		~UI(void);
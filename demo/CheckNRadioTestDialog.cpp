@@ -35,9 +35,10 @@ namespace AssortedWidgets
             m_gridLayout->setSpacer(4);
 
             m_closeButton=new Widgets::Button("Close");
-            m_checkButton1=new Widgets::CheckButton("Check 1");
+            m_checkButton1=new Widgets::CheckButton("Check 1",true);
             m_checkButton2=new Widgets::CheckButton("Check 2");
-            m_checkButton3=new Widgets::CheckButton("Check 3");
+            m_checkButton3=new Widgets::CheckButton("Check All");
+            m_checkButton3->setIndeterminate(true);
             m_radioGroup=new Widgets::RadioGroup();
             m_radioButton1=new Widgets::RadioButton("Radio 1",m_radioGroup);
             m_radioButton2=new Widgets::RadioButton("Radio 2",m_radioGroup);
@@ -57,6 +58,9 @@ namespace AssortedWidgets
 
 
             m_closeButton->mouseReleasedHandlerList.push_back(MOUSE_DELEGATE(CheckNRadioTestDialog::onClose));
+            m_checkButton1->mouseReleasedHandlerList.push_back(MOUSE_DELEGATE(CheckNRadioTestDialog::onCheckChild));
+            m_checkButton2->mouseReleasedHandlerList.push_back(MOUSE_DELEGATE(CheckNRadioTestDialog::onCheckChild));
+            m_checkButton3->mouseReleasedHandlerList.push_back(MOUSE_DELEGATE(CheckNRadioTestDialog::onCheckAll));
 		}
 
 		void CheckNRadioTestDialog::onClose(const Event::MouseEvent &e)
@@ -65,6 +69,24 @@ namespace AssortedWidgets
 			Close();
 		}
 
+        void CheckNRadioTestDialog::onCheckAll(const Event::MouseEvent &)
+		{
+            m_checkButton1->setCheck(m_checkButton3->isCheck());
+            m_checkButton2->setCheck(m_checkButton3->isCheck());
+		}
+
+        void CheckNRadioTestDialog::onCheckChild(const Event::MouseEvent &)
+		{
+            if(m_checkButton1->isCheck()!=m_checkButton2->isCheck())
+			{
+                m_checkButton3->setIndeterminate(true);
+			}
+			else
+			{
+                m_checkButton3->setCheck(m_checkButton1->isCheck());
+			}
+		}
+
 		CheckNRadioTestDialog::~CheckNRadioTestDialog(void)
 		{
             delete m_closeButton;
@@ -27,6 +27,8 @@ namespace AssortedWidgets
 		public:
 			CheckNRadioTestDialog(void);
 			void onClose(const Event::MouseEvent &e);
+			void onCheckAll(const Event::MouseEvent &e);
+			void onCheckChild(const Event::MouseEvent &e);
 		public:
 			~CheckNRadioTestDialog(void);
 		};
@@ -23,6 +23,7 @@ namespace AssortedWidgets
             m_centerPanel->setLayout(m_centerGridLayout);
 
             m_horizontalPBar=new Widgets::ProgressBar(0.0f,100.0f,0.0f);
+            m_horizontalPBar->setShowLabel(true);
             m_horizontalSBar=new Widgets::SlideBar(0.0f,100.0f,0.0f);
 
             m_centerPanel->add(m_horizontalPBar);
@@ -32,6 +33,8 @@ namespace AssortedWidgets
 
             m_verticalPBar=new Widgets::ProgressBar(0.0f,100.0f,0.0f,Widgets::ProgressBar::Vertical);
             m_verticalSBar=new Widgets::SlideBar(0.0f,100.0f,0.0f,Widgets::ProgressBar::Vertical);
+            m_verticalSBar->setStep(10.0f);
+            m_verticalSBar->setTickCount(11);
 
             m_verticalPBar->setLayoutProperty(Layout::BorderLayout::East);
             m_verticalSBar->setLayoutProperty(Layout::BorderLayout::East);